@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+use aoc_common::solution::Part;
+
+/// Answers confirmed on the website, kept per user next to the inputs.
+///
+/// ```toml
+/// [day_4.input]
+/// part1 = 25571
+///
+/// [day_4.example]
+/// part1 = 13
+/// part2 = 30
+/// ```
+///
+/// Each day is keyed by input name, the file stem of `input.txt` or `example_2.txt`.
+#[derive(Debug)]
+pub struct KnownAnswers {
+    path: PathBuf,
+    days: BTreeMap<String, BTreeMap<String, Parts>>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct Parts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Stored>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Stored>,
+}
+
+/// Integers are written as TOML integers when they fit, anything else as strings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Stored {
+    Integer(i64),
+    Text(String),
+}
+
+impl Parts {
+    fn get(&self, part: Part) -> Option<&Stored> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, stored: Stored) {
+        match part {
+            Part::One => self.part1 = Some(stored),
+            Part::Two => self.part2 = Some(stored),
+        }
+    }
+}
+
+impl From<&Stored> for Answer {
+    fn from(stored: &Stored) -> Self {
+        match stored {
+            Stored::Integer(v) => Answer::from(*v),
+            Stored::Text(s) => match s.parse::<Answer>() {
+                // Only numbers beyond i64 are written as text, and written exactly so:
+                // "0123" or "-5" in a string are text answers.
+                Ok(number @ (Answer::Signed(_) | Answer::Unsigned(_)))
+                    if number.to_string() == *s && s.parse::<i64>().is_err() =>
+                {
+                    number
+                }
+                _ => Answer::Text(s.clone()),
+            },
+        }
+    }
+}
+
+impl From<&Answer> for Stored {
+    fn from(answer: &Answer) -> Self {
+        let text = answer.to_string();
+        match (answer, text.parse::<i64>()) {
+            (Answer::Signed(_) | Answer::Unsigned(_), Ok(v)) => Stored::Integer(v),
+            _ => Stored::Text(text),
+        }
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day_{}", day)
+}
+
+impl KnownAnswers {
+    /// A missing file is an empty set of answers.
+    pub fn load(path: &Path) -> Result<Self> {
+        let days = match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|e| Error::Format {
+                path: path.display().to_string(),
+                message: e.to_string(),
+            })?,
+            Err(e) if e.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(Error::io(path.display().to_string(), e)),
+        };
+        Ok(KnownAnswers {
+            path: path.to_path_buf(),
+            days,
+        })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let content = toml::to_string_pretty(&self.days).map_err(|e| Error::Format {
+            path: self.path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        fs::write(&self.path, content).map_err(|e| Error::io(self.path.display().to_string(), e))
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: Part) -> Option<Answer> {
+        self.days
+            .get(&day_key(day))?
+            .get(input)?
+            .get(part)
+            .map(Answer::from)
+    }
+
+    pub fn set(&mut self, day: u8, input: &str, part: Part, answer: &Answer) {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(input.to_string())
+            .or_default()
+            .set(part, Stored::from(answer));
+    }
+
+    /// Leave out the answers of `day`, for this run only: `save` would drop them.
+    pub fn forget(&mut self, day: u8) {
+        self.days.remove(&day_key(day));
+    }
+
+    /// Every (day, input name, parts) with at least one known answer, in day order.
+    pub fn entries(&self) -> Vec<(u8, String, Vec<Part>)> {
+        let mut entries = vec![];
+        for (key, inputs) in &self.days {
+            let Some(day) = key.strip_prefix("day_").and_then(|d| d.parse::<u8>().ok()) else {
+                continue;
+            };
+            for (input, parts) in inputs {
+                let known: Vec<Part> = Part::ALL
+                    .into_iter()
+                    .filter(|&part| parts.get(part).is_some())
+                    .collect();
+                if !known.is_empty() {
+                    entries.push((day, input.clone(), known));
+                }
+            }
+        }
+        entries.sort_by_key(|(day, input, _)| (*day, input.clone()));
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KnownAnswers;
+    use aoc_common::answer::Answer;
+    use aoc_common::solution::Part;
+    use std::fs;
+
+    #[test]
+    fn test_missing_file_is_empty() {
+        let answers = KnownAnswers::load("/does/not/exist.toml".as_ref()).unwrap();
+        assert!(answers.entries().is_empty());
+        assert_eq!(answers.get(1, "input", Part::One), None);
+    }
+
+    #[test]
+    fn test_set_save_load() {
+        let path = std::env::temp_dir().join(format!("aoc_answers_{}.toml", std::process::id()));
+        let mut answers = KnownAnswers::load(&path).unwrap();
+        answers.set(4, "input", Part::Two, &Answer::from(8805731_u32));
+        answers.set(4, "example", Part::One, &Answer::from(13));
+        answers.set(10, "input", Part::One, &Answer::from(u128::MAX));
+        answers.set(2, "input", Part::One, &Answer::from("EZFKA"));
+        answers.set(2, "input", Part::Two, &Answer::from("0123"));
+        answers.save().unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("[day_4.input]\npart2 = 8805731"));
+
+        let answers = KnownAnswers::load(&path).unwrap();
+        assert_eq!(
+            answers.get(4, "input", Part::Two),
+            Some(Answer::from(8805731))
+        );
+        assert_eq!(answers.get(4, "input", Part::One), None);
+        assert_eq!(
+            answers.get(10, "input", Part::One),
+            Some(Answer::from(u128::MAX))
+        );
+        assert_eq!(
+            answers.get(2, "input", Part::One),
+            Some(Answer::from("EZFKA"))
+        );
+        assert_eq!(
+            answers.get(2, "input", Part::Two),
+            Some(Answer::Text(String::from("0123")))
+        );
+        assert_eq!(
+            answers.entries(),
+            vec![
+                (2, "input".to_string(), vec![Part::One, Part::Two]),
+                (4, "example".to_string(), vec![Part::One]),
+                (4, "input".to_string(), vec![Part::Two]),
+                (10, "input".to_string(), vec![Part::One]),
+            ]
+        );
+
+        let mut answers = answers;
+        answers.forget(4);
+        assert_eq!(answers.get(4, "input", Part::Two), None);
+        assert_eq!(answers.entries().len(), 2);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_file() {
+        let path = std::env::temp_dir().join(format!("aoc_bad_{}.toml", std::process::id()));
+        fs::write(&path, "[day_4.input\npart1 = 1").unwrap();
+        assert!(KnownAnswers::load(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+use crate::table::Table;
+use aoc_common::alloc::AllocStats;
+use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+use aoc_common::solution::{Part, Runner};
+
+/// Spread of the durations measured over several runs of the same stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `None` when there is nothing to summarize.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+        Some(Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+pub struct PartBench {
+    pub part: Part,
+    /// The answer of the first run; later runs are only timed.
+    pub answer: Result<Answer>,
+    pub stats: Stats,
+    /// What the last run allocated, once caches are warm.
+    pub alloc: Option<AllocStats>,
+}
+
+pub struct BenchReport {
+    pub runs: usize,
+    pub parse: Stats,
+    pub parse_alloc: Option<AllocStats>,
+    pub parts: Vec<PartBench>,
+}
+
+/// Solve `input` `runs` times and summarize how long each stage took.
+pub fn bench(runner: &dyn Runner, input: &str, parts: &[Part], runs: usize) -> Result<BenchReport> {
+    if runs == 0 {
+        return Err(Error::solve("a benchmark needs at least one run"));
+    }
+
+    let mut parse = vec![];
+    let mut parse_alloc = None;
+    let mut allocs: Vec<Option<AllocStats>> = parts.iter().map(|_| None).collect();
+    let mut answers: Vec<Option<Result<Answer>>> = parts.iter().map(|_| None).collect();
+    let mut samples: Vec<Vec<Duration>> = parts.iter().map(|_| vec![]).collect();
+
+    for _ in 0..runs {
+        let report = runner.run(input, parts)?;
+        parse.push(report.parse);
+        parse_alloc = report.parse_alloc;
+        for (i, part) in report.parts.into_iter().enumerate() {
+            samples[i].push(part.elapsed);
+            allocs[i] = part.alloc;
+            answers[i].get_or_insert(part.answer);
+        }
+    }
+
+    let parts = parts
+        .iter()
+        .zip(answers)
+        .zip(samples)
+        .zip(allocs)
+        .map(|(((&part, answer), samples), alloc)| PartBench {
+            part,
+            answer: answer.expect("every run solves every part"),
+            stats: Stats::from_samples(samples).expect("at least one run"),
+            alloc,
+        })
+        .collect();
+
+    Ok(BenchReport {
+        runs,
+        parse: Stats::from_samples(parse).expect("at least one run"),
+        parse_alloc,
+        parts,
+    })
+}
+
+impl BenchReport {
+    /// Allocation columns are only there when the counting allocator is installed.
+    pub fn table(&self) -> Table {
+        let counted = self.parse_alloc.is_some();
+        let mut headers = vec!["Stage", "Answer", "Runs", "Min", "Median", "Max"];
+        if counted {
+            headers.extend(["Allocs", "Bytes", "Peak"]);
+        }
+        let mut table = Table::new(&headers);
+        let mut row = |stage: String, answer: String, stats: &Stats, alloc: Option<AllocStats>| {
+            let mut cells = vec![
+                stage,
+                answer,
+                self.runs.to_string(),
+                format!("{:.1?}", stats.min),
+                format!("{:.1?}", stats.median),
+                format!("{:.1?}", stats.max),
+            ];
+            if counted {
+                let alloc = alloc.unwrap_or_default();
+                cells.extend([
+                    alloc.allocations.to_string(),
+                    bytes(alloc.bytes),
+                    bytes(alloc.peak),
+                ]);
+            }
+            table.row(cells);
+        };
+
+        row(
+            String::from("parse"),
+            String::new(),
+            &self.parse,
+            self.parse_alloc,
+        );
+        for part in &self.parts {
+            let answer = match &part.answer {
+                Ok(answer) => answer.to_string(),
+                Err(err) => err.to_string(),
+            };
+            row(
+                format!("part {}", part.part),
+                answer,
+                &part.stats,
+                part.alloc,
+            );
+        }
+        table
+    }
+}
+
+/// A byte count in the largest unit that keeps it above one.
+fn bytes(n: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = n as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", n)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bench, bytes, Stats};
+    use aoc_2023::day_4::Day4;
+    use aoc_common::answer::Answer;
+    use aoc_common::solution::Part;
+    use std::time::Duration;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(Stats::from_samples(vec![]), None);
+        assert_eq!(
+            Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(9), ms(2)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(9)
+            })
+        );
+    }
+
+    #[test]
+    fn test_bench_keeps_answers() {
+        let report = bench(
+            &Day4,
+            include_str!("../../aoc_2023/src/day_4/example.txt"),
+            &Part::ALL,
+            3,
+        )
+        .unwrap();
+        assert_eq!(report.runs, 3);
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(report.parts[1].answer.as_ref().unwrap(), &Answer::from(30));
+        assert!(report.parts[0].stats.min <= report.parts[0].stats.max);
+        assert!(bench(&Day4, "", &Part::ALL, 0).is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(bytes(0), "0 B");
+        assert_eq!(bytes(1023), "1023 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
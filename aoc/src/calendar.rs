@@ -0,0 +1,34 @@
+//! Every year the command line can solve, each from its own crate.
+
+use aoc_common::Day;
+
+/// The days of each year crate. A new year only needs its crate, a dependency and a line here.
+const YEARS: &[&[Day]] = &[aoc_2023::registry::DAYS];
+
+/// The year commands work on unless `--year` says otherwise.
+pub const LATEST_YEAR: u16 = 2023;
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    days(year).find(|d| d.day == day)
+}
+
+/// Every registered day of `year`, in order.
+pub fn days(year: u16) -> impl Iterator<Item = &'static Day> {
+    YEARS
+        .iter()
+        .flat_map(|days| days.iter())
+        .filter(move |d| d.year == year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{days, find, LATEST_YEAR};
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2023, 4).map(|d| (d.year, d.day)), Some((2023, 4)));
+        assert!(find(2022, 5).is_none());
+        assert!(days(LATEST_YEAR).count() >= 4);
+        assert_eq!(days(2015).count(), 0);
+    }
+}
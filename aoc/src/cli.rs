@@ -0,0 +1,423 @@
+use crate::calendar::LATEST_YEAR;
+use aoc_common::solution::Part;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+/// Advent of Code solutions.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Year of the calendar
+    #[arg(short, long, global = true, default_value_t = LATEST_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+    pub year: u16,
+
+    /// Directory holding a <year>/day_N/ input folder per year [default: $AOC_INPUT_DIR, then aoc_<year>/src/]
+    #[arg(long, global = true, value_name = "DIR")]
+    pub input_dir: Option<PathBuf>,
+
+    /// Configuration file with the session token [default: $AOC_CONFIG, then ~/.config/aoc/config.toml]
+    #[arg(long, global = true, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Show more diagnostics: -v for timings, -vv for each day's results, -vvv for every line
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Show fewer diagnostics: -q hides warnings, -qq hides errors too
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "verbose")]
+    pub quiet: u8,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solve one day of the calendar, or all of them
+    Run(RunArgs),
+    /// Check every confirmed answer still comes out the same
+    Verify(VerifyArgs),
+    /// Download a day's input from adventofcode.com, unless it is already there
+    Fetch(FetchArgs),
+    /// Solve a part on the day's input and send the answer to adventofcode.com
+    Submit(SubmitArgs),
+    /// Generate the module of a new day from a template and register it
+    New(NewArgs),
+    /// Write the examples of a puzzle page with their answers, and test them
+    Examples(ExamplesArgs),
+    /// Solve a day again whenever its input, examples or known answers change
+    Watch(WatchArgs),
+    /// Solve days several times and report how long each stage takes
+    Bench(BenchArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub solve: SolveArgs,
+
+    /// Record the answers of this run as confirmed, to catch regressions later
+    #[arg(long, conflicts_with_all = ["all", "input", "format", "option"])]
+    pub confirm: bool,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Solve one day and part at a time, for timings the others do not disturb
+    #[arg(long)]
+    pub sequential: bool,
+
+    /// Read the input one line at a time instead of loading it, for days 1, 2 and 4
+    #[arg(long, conflicts_with_all = ["all", "format"])]
+    pub stream: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// Only verify this day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Solve one day and part at a time, for timings the others do not disturb
+    #[arg(long)]
+    pub sequential: bool,
+}
+
+/// Output of the commands solving several parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A table for people to read
+    Text,
+    /// One JSON object per day and part, one per line
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct FetchArgs {
+    /// Day of the calendar, from 1 to 25
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+}
+
+#[derive(Debug, Args)]
+pub struct SubmitArgs {
+    /// Day of the calendar, from 1 to 25
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Part to submit (1 or 2)
+    #[arg(short, long)]
+    pub part: Part,
+}
+
+#[derive(Debug, Args)]
+pub struct NewArgs {
+    /// Day of the calendar, from 1 to 25
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Source directory holding lib.rs and registry.rs [default: aoc_<year>/src/]
+    #[arg(long, value_name = "DIR")]
+    pub src_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct ExamplesArgs {
+    /// Day of the calendar, from 1 to 25
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Saved puzzle page to read, `-` for stdin [default: fetched from adventofcode.com]
+    #[arg(long, value_name = "FILE")]
+    pub page: Option<PathBuf>,
+
+    /// Source directory holding the day modules [default: aoc_<year>/src/]
+    #[arg(long, value_name = "DIR")]
+    pub src_dir: Option<PathBuf>,
+
+    /// Overwrite example files that differ from the page
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// Day of the calendar, from 1 to 25
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Only solve this part (1 or 2); both parts are solved by default
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// How often to look for changes, in milliseconds
+    #[arg(long, default_value_t = 500, value_parser = clap::value_parser!(u64).range(50..))]
+    pub interval: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub solve: SolveArgs,
+
+    /// How many times to solve
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub runs: u32,
+}
+
+/// Which day and parts to solve, and on which input.
+#[derive(Debug, Args)]
+pub struct SolveArgs {
+    /// Day of the calendar, from 1 to 25
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+    pub day: Option<u8>,
+
+    /// Every registered day instead of a single one
+    #[arg(short, long, conflicts_with_all = ["day", "input"])]
+    pub all: bool,
+
+    /// Only solve this part (1 or 2); both parts are solved by default
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// Read the puzzle input from this file instead of the day's input.txt, `-` for stdin
+    #[arg(short, long, conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+
+    /// Use the day's example (example_<part>.txt, or example.txt) instead of input.txt
+    #[arg(short, long)]
+    pub example: bool,
+
+    /// Solve the day another way it supports, such as day 1 of 2023 with the digit words
+    /// of a language (french, german) or of a file of `token = digit` lines
+    #[arg(long, value_name = "VALUE", conflicts_with = "all")]
+    pub option: Option<String>,
+}
+
+impl SolveArgs {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cli, Command, Format, LATEST_YEAR};
+    use aoc_common::solution::Part;
+    use clap::{CommandFactory, Parser};
+
+    #[test]
+    fn test_cli_is_consistent() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_run() {
+        let cli = Cli::parse_from(["aoc", "run", "--day", "4", "--part", "2", "--example"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert_eq!(args.solve.day, Some(4));
+        assert_eq!(args.solve.parts(), vec![Part::Two]);
+        assert!(args.solve.example);
+        assert!(args.solve.input.is_none());
+        assert!(args.solve.option.is_none());
+        assert!(cli.input_dir.is_none());
+    }
+
+    #[test]
+    fn test_parse_stream() {
+        let cli = Cli::parse_from(["aoc", "run", "-d", "2", "--stream", "-i", "-"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert!(args.stream);
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "--stream"]).is_err());
+        assert!(
+            Cli::try_parse_from(["aoc", "run", "-d", "2", "--stream", "--format", "json"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_option() {
+        let cli = Cli::parse_from(["aoc", "run", "-d", "1", "--option", "french"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert_eq!(args.solve.option.as_deref(), Some("french"));
+        assert!(
+            Cli::try_parse_from(["aoc", "run", "-d", "1", "--option", "de", "--confirm"]).is_err()
+        );
+        assert!(Cli::try_parse_from(["aoc", "run", "--option", "de"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "bench", "--all", "--option", "de"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        let cli = Cli::parse_from(["aoc", "run", "--all", "--part", "1"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert!(args.solve.all);
+        assert_eq!(args.solve.day, None);
+        assert_eq!(args.solve.parts(), vec![Part::One]);
+    }
+
+    #[test]
+    fn test_parse_verbosity() {
+        let cli = Cli::parse_from(["aoc", "run", "-d", "1", "-vv"]);
+        assert_eq!((cli.verbose, cli.quiet), (2, 0));
+        let cli = Cli::parse_from(["aoc", "-q", "verify"]);
+        assert_eq!((cli.verbose, cli.quiet), (0, 1));
+        assert!(Cli::try_parse_from(["aoc", "verify", "-v", "-q"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_confirm() {
+        let cli = Cli::parse_from(["aoc", "run", "-d", "2", "--confirm"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert!(args.confirm);
+        assert!(!args.sequential);
+        let cli = Cli::parse_from(["aoc", "run", "--all", "--sequential"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert!(args.sequential);
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "--confirm"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "-d", "2", "-i", "-", "--confirm"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let cli = Cli::parse_from(["aoc", "run", "-d", "2"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert_eq!(args.format, Format::Text);
+        let cli = Cli::parse_from(["aoc", "verify", "--format", "json"]);
+        let Command::Verify(args) = cli.command else {
+            panic!("expected the verify command");
+        };
+        assert_eq!(args.format, Format::Json);
+        assert!(!args.sequential);
+        assert!(Cli::try_parse_from(["aoc", "run", "-d", "2", "--format", "xml"]).is_err());
+        assert!(
+            Cli::try_parse_from(["aoc", "run", "-d", "2", "--format", "json", "--confirm"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        let cli = Cli::parse_from(["aoc", "fetch", "-d", "5", "--config", "aoc.toml"]);
+        let Command::Fetch(args) = cli.command else {
+            panic!("expected the fetch command");
+        };
+        assert_eq!(args.day, 5);
+        assert_eq!(
+            cli.config.as_deref(),
+            Some(std::path::Path::new("aoc.toml"))
+        );
+        assert!(Cli::try_parse_from(["aoc", "fetch"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        let cli = Cli::parse_from(["aoc", "submit", "-d", "4", "-p", "2"]);
+        let Command::Submit(args) = cli.command else {
+            panic!("expected the submit command");
+        };
+        assert_eq!((args.day, args.part), (4, Part::Two));
+        assert!(Cli::try_parse_from(["aoc", "submit", "-d", "4"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        let cli = Cli::parse_from(["aoc", "new", "--day", "5"]);
+        let Command::New(args) = cli.command else {
+            panic!("expected the new command");
+        };
+        assert_eq!(args.day, 5);
+        assert!(args.src_dir.is_none());
+        assert!(Cli::try_parse_from(["aoc", "new", "-d", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        let cli = Cli::parse_from(["aoc", "examples", "-d", "1", "--page", "day1.html"]);
+        let Command::Examples(args) = cli.command else {
+            panic!("expected the examples command");
+        };
+        assert_eq!(args.day, 1);
+        assert_eq!(
+            args.page.as_deref(),
+            Some(std::path::Path::new("day1.html"))
+        );
+        assert!(!args.force);
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        let cli = Cli::parse_from(["aoc", "watch", "-d", "1", "-p", "2"]);
+        let Command::Watch(args) = cli.command else {
+            panic!("expected the watch command");
+        };
+        assert_eq!(
+            (args.day, args.part, args.interval),
+            (1, Some(Part::Two), 500)
+        );
+        assert!(Cli::try_parse_from(["aoc", "watch", "-d", "1", "--interval", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let cli = Cli::parse_from(["aoc", "bench", "--day", "3", "-n", "25"]);
+        let Command::Bench(args) = cli.command else {
+            panic!("expected the bench command");
+        };
+        assert_eq!(args.solve.day, Some(3));
+        assert_eq!(args.solve.parts(), Part::ALL.to_vec());
+        assert_eq!(args.runs, 25);
+        assert!(Cli::try_parse_from(["aoc", "bench", "-d", "3", "-n", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        let cli = Cli::parse_from(["aoc", "run", "--day", "4"]);
+        assert_eq!(cli.year, LATEST_YEAR);
+        let cli = Cli::parse_from(["aoc", "--year", "2022", "run", "--day", "5"]);
+        assert_eq!(cli.year, 2022);
+        let cli = Cli::parse_from(["aoc", "run", "-y", "2022", "-d", "5"]);
+        assert_eq!(cli.year, 2022);
+        assert!(Cli::try_parse_from(["aoc", "run", "-y", "1999", "-d", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_input_dir_anywhere() {
+        let cli = Cli::parse_from(["aoc", "run", "-d", "1", "--input-dir", "/tmp/aoc"]);
+        assert_eq!(
+            cli.input_dir.as_deref(),
+            Some(std::path::Path::new("/tmp/aoc"))
+        );
+    }
+
+    #[test]
+    fn test_reject_bad_arguments() {
+        assert!(Cli::try_parse_from(["aoc", "run", "--day", "26"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "--day", "1", "--part", "3"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "-d", "1", "-e", "-i", "x.txt"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "-d", "1"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "-i", "x.txt"]).is_err());
+    }
+}
@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use ureq::Agent;
+
+use crate::submit::{self, Verdict};
+use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+use aoc_common::solution::Part;
+
+/// Identifies the tool to the website, as its maintainers ask automated clients to do.
+pub const USER_AGENT: &str = concat!(
+    "github.com/thmsgntz/advent_of_codes ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Talks to adventofcode.com, or to whatever `base_url` points at.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        self.fetch(&format!("{}/{}/day/{}/input", self.base_url, year, day))
+    }
+
+    /// The puzzle page; part 2 is only in it once part 1 is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String> {
+        self.fetch(&format!("{}/{}/day/{}", self.base_url, year, day))
+    }
+
+    fn fetch(&self, url: &str) -> Result<String> {
+        let (status, body) = self.get(url)?;
+
+        match status {
+            200 => Ok(body),
+            404 => Err(Error::Http {
+                url: url.to_string(),
+                message: String::from("not found, the puzzle is probably not unlocked yet"),
+            }),
+            // The website answers 400 or 500 to an expired or mistyped session cookie.
+            400 | 401 | 403 | 500 => Err(expired_session(status)),
+            _ => Err(unexpected(url.to_string(), status, &body)),
+        }
+    }
+
+    /// Post `answer` for one part and read the website's verdict.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<Verdict> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())]);
+        let (status, body) = read_response(&url, response)?;
+
+        match status {
+            200 => Ok(submit::parse_verdict(&body)),
+            400 | 401 | 403 | 500 => Err(expired_session(status)),
+            _ => Err(unexpected(url, status, &body)),
+        }
+    }
+
+    fn get(&self, url: &str) -> Result<(u16, String)> {
+        let response = self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(url, response)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: std::result::Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<(u16, String)> {
+    let http_error = |e: ureq::Error| Error::Http {
+        url: url.to_string(),
+        message: e.to_string(),
+    };
+    let mut response = response.map_err(http_error)?;
+    let status = response.status().as_u16();
+    let body = response.body_mut().read_to_string().map_err(http_error)?;
+    Ok((status, body))
+}
+
+fn expired_session(status: u16) -> Error {
+    Error::Session(format!(
+        "the website refused the session token (HTTP {}), it has probably expired: \
+         copy a fresh `session` cookie from the browser",
+        status
+    ))
+}
+
+fn unexpected(url: String, status: u16, body: &str) -> Error {
+    let first_line = body.lines().next().unwrap_or("");
+    Error::Http {
+        url,
+        message: format!("unexpected HTTP {}: {}", status, first_line),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Client, USER_AGENT};
+    use crate::mock_server::MockServer;
+    use crate::submit::Verdict;
+    use aoc_common::answer::Answer;
+    use aoc_common::error::Error;
+    use aoc_common::solution::Part;
+
+    #[test]
+    fn test_input() {
+        let server = MockServer::start(vec![(200, "1abc2\ntreb7uchet\n")]);
+        let client = Client::new(&server.url, "abc123");
+
+        assert_eq!(client.input(2023, 1).unwrap(), "1abc2\ntreb7uchet\n");
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc123"));
+        assert!(requests[0].contains(USER_AGENT));
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let server = MockServer::start(vec![(200, "<article><p>--- Day 1 ---</p></article>")]);
+        let client = Client::new(&server.url, "abc123");
+
+        assert!(client.puzzle(2023, 1).unwrap().contains("Day 1"));
+        assert!(server.requests()[0].starts_with("GET /2023/day/1 HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let server = MockServer::start(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+        ]);
+        let client = Client::new(&server.url, "expired");
+
+        assert!(matches!(client.input(2023, 1), Err(Error::Session(_))));
+        assert!(matches!(client.input(2023, 25), Err(Error::Http { .. })));
+        server.requests();
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(vec![(
+            200,
+            "<main><article><p>That's the right answer!  You are one gold star closer.</p></article></main>",
+        )]);
+        let client = Client::new(&server.url, "abc123");
+
+        let verdict = client.submit(2023, 4, Part::Two, &Answer::from(8805731));
+        assert_eq!(verdict.unwrap(), Verdict::Correct);
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2023/day/4/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc123"));
+        assert!(requests[0].ends_with("level=2&answer=8805731"));
+    }
+}
@@ -0,0 +1,19 @@
+use super::{selected_days, targets};
+use crate::bench::bench;
+use crate::cli::BenchArgs;
+use aoc_common::error::Result;
+use aoc_common::input::InputLocator;
+
+pub fn run(year: u16, locator: &InputLocator, args: &BenchArgs) -> Result<()> {
+    for day in selected_days(year, &args.solve)? {
+        for target in targets(locator, &args.solve, day.day) {
+            let input = target.source.read()?;
+            let report = bench(&*day.solution, &input, &target.parts, args.runs as usize)
+                .map_err(|e| e.in_file(target.source.describe()))?;
+
+            println!("Day {} on {}", day.day, target.source.describe());
+            print!("{}", report.table());
+        }
+    }
+    Ok(())
+}
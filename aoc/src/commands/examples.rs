@@ -0,0 +1,114 @@
+use crate::answers::KnownAnswers;
+use crate::cli::ExamplesArgs;
+use crate::client::Client;
+use crate::config::Config;
+use crate::puzzle;
+use crate::scaffold;
+use aoc_common::error::{Error, Result};
+use aoc_common::input::year_dir;
+use aoc_common::input::{InputLocator, Source};
+
+/// The example files and their answers both go under the source directory, where the
+/// generated tests read them. `config` is only loaded when the page has to be fetched.
+pub fn run(
+    year: u16,
+    locator: &InputLocator,
+    config: impl FnOnce() -> Result<Config>,
+    args: &ExamplesArgs,
+) -> Result<()> {
+    let page = match &args.page {
+        Some(path) => Source::from_arg(path).read()?,
+        None => {
+            let config = config()?;
+            Client::new(config.base_url(), config.session()?).puzzle(year, args.day)?
+        }
+    };
+
+    let examples = puzzle::examples(&page);
+    if examples.is_empty() {
+        return Err(Error::Scaffold(String::from(
+            "no example in the page; is it the puzzle page, fetched with a valid session?",
+        )));
+    }
+
+    let src = match &args.src_dir {
+        Some(dir) => dir.clone(),
+        None => year_dir(year)?,
+    };
+    for path in scaffold::add_examples(&src, year, args.day, &examples, args.force)? {
+        println!("Wrote {}", path.display());
+    }
+
+    let with_examples = InputLocator::new(&src);
+    let mut known = KnownAnswers::load(&with_examples.answers_file())?;
+    for example in &examples {
+        let name = scaffold::example_name(&examples, example);
+        match &example.answer {
+            Some(answer) => {
+                println!(
+                    "Day {} part {} on {}: {}",
+                    args.day, example.part, name, answer
+                );
+                known.set(args.day, &name, example.part, answer);
+            }
+            None => println!(
+                "Day {} part {} on {}: no answer found",
+                args.day, example.part, name
+            ),
+        }
+    }
+    known.save()?;
+    println!("Recorded in {}", with_examples.answers_file().display());
+    if *locator != with_examples {
+        println!(
+            "`run --example` reads {} instead, copy them there to solve them",
+            locator.day_dir(args.day).display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::cli::ExamplesArgs;
+    use aoc_common::input::InputLocator;
+    use std::fs;
+
+    const PAGE: &str = r#"<article><pre><code>1abc2
+</code></pre><p>Adding these together produces <code><em>12</em></code>.</p></article>"#;
+
+    #[test]
+    fn test_examples_and_answers_go_together() {
+        let dir = std::env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        let src = dir.join("src");
+        fs::create_dir_all(src.join("day_5")).unwrap();
+        fs::write(src.join("day_5/mod.rs"), "pub struct Day5;\n").unwrap();
+        fs::write(src.join("registry.rs"), "").unwrap();
+        fs::write(dir.join("page.html"), PAGE).unwrap();
+
+        let args = ExamplesArgs {
+            day: 5,
+            page: Some(dir.join("page.html")),
+            src_dir: Some(src.clone()),
+            force: false,
+        };
+        let inputs = InputLocator::new(dir.join("inputs"));
+        run(2023, &inputs, || unreachable!("the page is saved"), &args).unwrap();
+
+        let with_examples = InputLocator::new(&src);
+        assert_eq!(
+            fs::read_to_string(with_examples.named(5, "example")).unwrap(),
+            "1abc2\n"
+        );
+        let answers = fs::read_to_string(with_examples.answers_file()).unwrap();
+        assert!(
+            answers.contains("[day_5.example]\npart1 = 12"),
+            "{}",
+            answers
+        );
+        assert!(!inputs.answers_file().exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
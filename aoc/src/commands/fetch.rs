@@ -0,0 +1,94 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::cli::FetchArgs;
+use crate::client::Client;
+use crate::config::Config;
+use aoc_common::error::{Error, Result};
+use aoc_common::input::InputLocator;
+
+pub fn run(year: u16, locator: &InputLocator, config: &Config, args: &FetchArgs) -> Result<()> {
+    let client = Client::new(config.base_url(), config.session()?);
+    fetch_input(&client, locator, year, args.day)?;
+    Ok(())
+}
+
+/// Download the input unless it is already on disk; it never changes once published.
+pub fn fetch_input(client: &Client, locator: &InputLocator, year: u16, day: u8) -> Result<PathBuf> {
+    let path = locator.input(day);
+    if path.exists() {
+        println!("{} is already there", path.display());
+        return Ok(path);
+    }
+
+    let input = client.input(year, day)?;
+    // Written aside then renamed, so that an interrupted write is never taken for the input.
+    let partial = locator.day_dir(day).join(".input.txt.part");
+    let write = || {
+        fs::create_dir_all(locator.day_dir(day))?;
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)
+    };
+    if let Err(e) = write() {
+        let _ = fs::remove_file(&partial);
+        return Err(Error::io(path.display().to_string(), e));
+    }
+    println!("Saved {}", path.display());
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fetch_input;
+    use crate::client::Client;
+    use crate::mock_server::MockServer;
+    use aoc_common::input::InputLocator;
+    use std::fs;
+
+    #[test]
+    fn test_fetch_is_cached() {
+        let dir = std::env::temp_dir().join(format!("aoc_fetch_{}", std::process::id()));
+        let locator = InputLocator::new(&dir);
+        // A single response: a second request would find nobody listening.
+        let server = MockServer::start(vec![(200, "Card 1: 1 | 1\n")]);
+        let client = Client::new(&server.url, "abc123");
+
+        let path = fetch_input(&client, &locator, 2023, 4).unwrap();
+        assert_eq!(path, dir.join("day_4/input.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Card 1: 1 | 1\n");
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(fs::read_dir(dir.join("day_4")).unwrap().count(), 1);
+
+        assert_eq!(fetch_input(&client, &locator, 2023, 4).unwrap(), path);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_partial_file_is_not_the_input() {
+        let dir = std::env::temp_dir().join(format!("aoc_fetch_part_{}", std::process::id()));
+        let locator = InputLocator::new(&dir);
+        fs::create_dir_all(locator.day_dir(6)).unwrap();
+        fs::write(locator.day_dir(6).join(".input.txt.part"), "Time: 7").unwrap();
+        let server = MockServer::start(vec![(200, "Time: 7 15 30\n")]);
+        let client = Client::new(&server.url, "abc123");
+
+        let path = fetch_input(&client, &locator, 2023, 6).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Time: 7 15 30\n");
+        assert!(!locator.day_dir(6).join(".input.txt.part").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_failed_fetch_writes_nothing() {
+        let dir = std::env::temp_dir().join(format!("aoc_fetch_fail_{}", std::process::id()));
+        let locator = InputLocator::new(&dir);
+        let server = MockServer::start(vec![(500, "Internal Server Error")]);
+        let client = Client::new(&server.url, "expired");
+
+        assert!(fetch_input(&client, &locator, 2023, 5).is_err());
+        assert!(!locator.input(5).exists());
+        server.requests();
+    }
+}
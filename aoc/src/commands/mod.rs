@@ -0,0 +1,188 @@
+pub mod bench;
+pub mod examples;
+pub mod fetch;
+pub mod new;
+pub mod run;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
+use crate::answers::KnownAnswers;
+use crate::calendar;
+use crate::cli::{Format, SolveArgs};
+use crate::json;
+use crate::logger;
+use crate::summary::{input_hash, Summary};
+use aoc_common::error::{Error, Result};
+use aoc_common::input::{InputLocator, Source};
+use aoc_common::solution::{Part, RunReport};
+use aoc_common::Day;
+use rayon::prelude::*;
+
+/// The day of `year` given with `--day`, set up with `--option` if there is one,
+/// or every registered day of `year` with `--all`.
+fn selected_days(year: u16, args: &SolveArgs) -> Result<Vec<Day>> {
+    let day = match args.day {
+        Some(day) if !args.all => day,
+        _ => return Ok(calendar::days(year).cloned().collect()),
+    };
+    let found = calendar::find(year, day).ok_or(Error::MissingDay { year, day })?;
+    match &args.option {
+        Some(option) => Ok(vec![found.with_option(option)?]),
+        None => Ok(vec![found.clone()]),
+    }
+}
+
+/// One input to solve, and which parts to solve on it.
+struct Target {
+    source: Source,
+    /// Input name in the answers file, when the file comes from the input directory.
+    name: Option<String>,
+    parts: Vec<Part>,
+}
+
+/// Solve each day on its target, side by side on the thread pool unless `sequential`,
+/// then add them to `summary` in the order given, checked against `known`.
+fn summarize(
+    summary: &mut Summary,
+    jobs: &[(&Day, Target)],
+    known: &KnownAnswers,
+    sequential: bool,
+) {
+    let add = |summary: &mut Summary, day: &Day, target: &Target, solved| {
+        let (hash, report) = solved;
+        let name = target.name.as_deref().unwrap_or("-");
+        summary.add(day.day, name, hash, &target.parts, report, |part| {
+            known.get(day.day, name, part)
+        });
+    };
+
+    if sequential {
+        for (day, target) in jobs {
+            add(summary, day, target, solve(day, target, false));
+        }
+        return;
+    }
+
+    // Records are held back so that each day's come out together, in day order.
+    logger::hold();
+    let solved: Vec<_> = jobs
+        .par_iter()
+        .map(|(day, target)| solve(day, target, true))
+        .collect();
+    let mut held = logger::release();
+    for ((day, target), solved) in jobs.iter().zip(solved) {
+        held.print(&format!("aoc_{}::day_{}", day.year, day.day));
+        add(summary, day, target, solved);
+    }
+    held.print_rest();
+}
+
+/// Read `target` and solve it, with the hash of the input when it could be read.
+fn solve(day: &Day, target: &Target, parallel: bool) -> (Option<String>, Result<RunReport>) {
+    let input = match target.source.read() {
+        Ok(input) => input,
+        Err(err) => return (None, Err(err)),
+    };
+    let report = if parallel {
+        day.solution.run_parallel(&input, &target.parts)
+    } else {
+        day.solution.run(&input, &target.parts)
+    };
+    (
+        Some(input_hash(&input)),
+        report.map_err(|e| e.in_file(target.source.describe())),
+    )
+}
+
+/// The table, or one JSON record per row.
+fn print_summary(summary: &Summary, year: u16, format: Format) {
+    match format {
+        Format::Text => print!("{}", summary.table()),
+        Format::Json => print!("{}", json::lines(year, summary)),
+    }
+}
+
+/// Which input to read for each requested part. Examples can differ between parts.
+fn targets(locator: &InputLocator, args: &SolveArgs, day: u8) -> Vec<Target> {
+    if let Some(path) = &args.input {
+        return vec![Target {
+            source: Source::from_arg(path),
+            name: None,
+            parts: args.parts(),
+        }];
+    }
+    if !args.example {
+        return vec![Target {
+            source: Source::File(locator.input(day)),
+            name: Some(String::from("input")),
+            parts: args.parts(),
+        }];
+    }
+
+    example_targets(locator, day, &args.parts())
+}
+
+/// The examples of `parts`, one target per example file.
+fn example_targets(locator: &InputLocator, day: u8, parts: &[Part]) -> Vec<Target> {
+    let mut targets: Vec<Target> = vec![];
+    for &part in parts {
+        let path = locator.example(day, part);
+        let source = Source::File(path.clone());
+
+        match targets.iter_mut().find(|t| t.source == source) {
+            Some(target) => target.parts.push(part),
+            None => targets.push(Target {
+                source,
+                name: path.file_stem().map(|s| s.to_string_lossy().into_owned()),
+                parts: vec![part],
+            }),
+        }
+    }
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::selected_days;
+    use crate::cli::SolveArgs;
+    use aoc_common::answer::Answer;
+    use aoc_common::error::Error;
+    use aoc_common::solution::Part;
+    use std::fs;
+
+    fn args(day: u8, option: &str) -> SolveArgs {
+        SolveArgs {
+            day: Some(day),
+            all: false,
+            part: None,
+            input: None,
+            example: false,
+            option: Some(option.to_string()),
+        }
+    }
+
+    fn part2(args: &SolveArgs, input: &str) -> Answer {
+        let days = selected_days(2023, args).unwrap();
+        let report = days[0].solution.run(input, &[Part::Two]).unwrap();
+        report.parts[0].answer.as_ref().unwrap().clone()
+    }
+
+    #[test]
+    fn test_option() {
+        assert_eq!(part2(&args(1, "french"), "deuxone3un\n"), Answer::from(21));
+        assert_eq!(part2(&args(1, "en"), "deuxone3un\n"), Answer::from(13));
+
+        let path = std::env::temp_dir().join(format!("aoc_vocabulary_{}.txt", std::process::id()));
+        fs::write(&path, "# Roman\ni = 1\nv = 5\n").unwrap();
+        let from_file = args(1, path.to_str().unwrap());
+        assert_eq!(part2(&from_file, "xv2i\n"), Answer::from(51));
+        fs::remove_file(&path).unwrap();
+
+        assert!(selected_days(2023, &args(1, "klingon")).is_err());
+        assert!(matches!(
+            selected_days(2023, &args(3, "french")),
+            Err(Error::BadOption(_))
+        ));
+    }
+}
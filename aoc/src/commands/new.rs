@@ -0,0 +1,20 @@
+use crate::cli::NewArgs;
+use crate::scaffold;
+use aoc_common::error::Result;
+use aoc_common::input::year_dir;
+
+pub fn run(year: u16, args: &NewArgs) -> Result<()> {
+    let src = match &args.src_dir {
+        Some(dir) => dir.clone(),
+        None => year_dir(year)?,
+    };
+
+    for path in scaffold::scaffold(&src, year, args.day)? {
+        println!("Wrote {}", path.display());
+    }
+    println!(
+        "Day {} is registered: `cargo run -- run --day {}` once it is solved",
+        args.day, args.day
+    );
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use super::{print_summary, selected_days, summarize, targets, Target};
+use crate::answers::KnownAnswers;
+use crate::cli::{Format, RunArgs};
+use crate::summary::{Check, Summary};
+use aoc_common::error::{Error, Result};
+use aoc_common::input::{InputLocator, Source};
+use aoc_common::solution::{PartReport, RunReport};
+use aoc_common::Day;
+use log::info;
+use std::time::{Duration, Instant};
+
+pub fn run(year: u16, locator: &InputLocator, args: &RunArgs) -> Result<()> {
+    let mut known = KnownAnswers::load(&locator.answers_file())?;
+    if let (Some(_), Some(day)) = (&args.solve.option, args.solve.day) {
+        // The known answers are the puzzle's, solved without the option.
+        known.forget(day);
+    }
+    let days = selected_days(year, &args.solve)?;
+
+    if args.solve.all || args.format == Format::Json {
+        return run_summary(year, locator, args, &days, &known);
+    }
+
+    let mut failure = None;
+    let mut confirmed = false;
+    for day in &days {
+        for target in targets(locator, &args.solve, day.day) {
+            let report = if args.stream {
+                stream(day, &target)?
+            } else {
+                let input = target.source.read()?;
+                let report = if args.sequential {
+                    day.solution.run(&input, &target.parts)
+                } else {
+                    day.solution.run_parallel(&input, &target.parts)
+                };
+                report.map_err(|e| e.in_file(target.source.describe()))?
+            };
+
+            if !args.stream {
+                info!("Day {} parse: {:.1?}", day.day, report.parse);
+            }
+            for part in report.parts {
+                let answer = match part.answer {
+                    Ok(answer) => answer,
+                    Err(Error::NotSolved(_)) if args.solve.part.is_none() => {
+                        // A full run may leave a part out; only asking for it is an error.
+                        println!("Day {} part {}: not solved yet", day.day, part.part);
+                        continue;
+                    }
+                    Err(err) => {
+                        failure.get_or_insert(err);
+                        continue;
+                    }
+                };
+
+                let expected = target
+                    .name
+                    .as_deref()
+                    .and_then(|name| known.get(day.day, name, part.part));
+                let check = match Check::against(&answer, expected.as_ref()) {
+                    Check::Unknown => String::new(),
+                    Check::Match => String::from(" ok"),
+                    Check::Mismatch { expected } => {
+                        failure.get_or_insert(Error::Regression(format!(
+                            "day {} part {} regressed: {} instead of {}",
+                            day.day, part.part, answer, expected
+                        )));
+                        format!(" REGRESSION, expected {}", expected)
+                    }
+                };
+                println!(
+                    "Day {} part {}: {} ({:.1?}){}",
+                    day.day, part.part, answer, part.elapsed, check
+                );
+
+                if let (true, Some(name)) = (args.confirm, &target.name) {
+                    known.set(day.day, name, part.part, &answer);
+                    confirmed = true;
+                }
+            }
+        }
+    }
+
+    if confirmed {
+        known.save()?;
+        println!("Recorded in {}", locator.answers_file().display());
+    }
+
+    match failure {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Solve each part in its own pass over the input, which is never loaded whole.
+fn stream(day: &Day, target: &Target) -> Result<RunReport> {
+    let Some(streaming) = day.solution.streaming() else {
+        return Err(Error::Solve(format!(
+            "day {} needs its whole input, it cannot be streamed",
+            day.day
+        )));
+    };
+    if target.source == Source::Stdin && target.parts.len() > 1 {
+        return Err(Error::Solve(String::from(
+            "stdin is read only once, stream one --part of it",
+        )));
+    }
+
+    let parts = target
+        .parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = target
+                .source
+                .reader()
+                .and_then(|mut reader| streaming.solve_reader(&mut reader, part))
+                .map_err(|e| e.in_file(target.source.describe()));
+            PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+                alloc: None,
+            }
+        })
+        .collect();
+    Ok(RunReport {
+        parse: Duration::ZERO,
+        parse_alloc: None,
+        parts,
+    })
+}
+
+/// Solve every day, keep going past failures and print all the results at the end.
+fn run_summary(
+    year: u16,
+    locator: &InputLocator,
+    args: &RunArgs,
+    days: &[Day],
+    known: &KnownAnswers,
+) -> Result<()> {
+    let jobs: Vec<_> = days
+        .iter()
+        .flat_map(|day| {
+            targets(locator, &args.solve, day.day)
+                .into_iter()
+                .map(move |target| (day, target))
+        })
+        .collect();
+    let mut summary = Summary::default();
+    summarize(&mut summary, &jobs, known, args.sequential);
+
+    print_summary(&summary, year, args.format);
+    if summary.has_failures() {
+        return Err(Error::Regression(String::from(
+            "some days failed or gave a wrong answer",
+        )));
+    }
+    Ok(())
+}
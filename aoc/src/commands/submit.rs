@@ -0,0 +1,182 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::answers::KnownAnswers;
+use crate::calendar;
+use crate::cli::SubmitArgs;
+use crate::client::Client;
+use crate::config::Config;
+use crate::submit::{Hint, Submissions, Verdict};
+use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+use aoc_common::input::{InputLocator, Source};
+use aoc_common::solution::Part;
+use log::info;
+
+pub fn run(year: u16, locator: &InputLocator, config: &Config, args: &SubmitArgs) -> Result<()> {
+    let (day, part) = (args.day, args.part);
+    let found = calendar::find(year, day).ok_or(Error::MissingDay { year, day })?;
+    let client = Client::new(config.base_url(), config.session()?);
+
+    let source = Source::File(locator.input(day));
+    let answer = found
+        .solve(part, &source.read()?)
+        .map_err(|e| e.in_file(source.describe()))?;
+
+    let mut known = KnownAnswers::load(&locator.answers_file())?;
+    if let Some(accepted) = known.get(day, "input", part) {
+        if accepted == answer {
+            println!("Day {} part {}: {} was already accepted", day, part, answer);
+            return Ok(());
+        }
+        return Err(Error::Submit(format!(
+            "day {} part {} was already accepted with {}, not sending {}",
+            day, part, accepted, answer
+        )));
+    }
+
+    let mut submissions = Submissions::load(&locator.submissions_file())?;
+    let verdict = submit_answer(&client, &mut submissions, year, day, part, &answer, now());
+    submissions.save()?;
+
+    match verdict? {
+        Verdict::Correct => {
+            known.set(day, "input", part, &answer);
+            known.save()?;
+            println!("Day {} part {}: {} is the right answer", day, part, answer);
+            println!("Recorded in {}", locator.answers_file().display());
+            Ok(())
+        }
+        Verdict::Wrong { hint, wait } => {
+            let hint = match hint {
+                Some(Hint::TooHigh) => ", too high",
+                Some(Hint::TooLow) => ", too low",
+                None => "",
+            };
+            let wait = wait
+                .map(|wait| format!("; wait {:?} before the next guess", wait))
+                .unwrap_or_default();
+            Err(Error::Submit(format!(
+                "{} is not the right answer{}{}",
+                answer, hint, wait
+            )))
+        }
+        Verdict::TooSoon { wait } => Err(Error::Submit(format!(
+            "the website asks to wait {:?} before the next answer",
+            wait
+        ))),
+        Verdict::WrongLevel => Err(Error::Submit(format!(
+            "day {} part {} takes no answer: it is already solved, or part 1 is not",
+            day, part
+        ))),
+        Verdict::Unknown(text) => Err(Error::Submit(format!(
+            "could not read the verdict: {}",
+            text
+        ))),
+    }
+}
+
+/// Send `answer` unless it is known to be wrong or the website asked to wait,
+/// then remember what the website said. `now` is in seconds since the Unix epoch.
+pub fn submit_answer(
+    client: &Client,
+    submissions: &mut Submissions,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    now: u64,
+) -> Result<Verdict> {
+    if let Some(reason) = submissions.refusal(year, day, part, answer) {
+        return Err(Error::Submit(format!("not sending: {}", reason)));
+    }
+    if let Some(wait) = submissions.cooldown(now) {
+        return Err(Error::Submit(format!(
+            "not sending: the website asked to wait {:?} more",
+            wait
+        )));
+    }
+
+    let verdict = client.submit(year, day, part, answer)?;
+    info!("Day {} part {}: {} -> {:?}", day, part, answer, verdict);
+    submissions.record(year, day, part, answer, &verdict, now);
+    Ok(verdict)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::submit_answer;
+    use crate::client::Client;
+    use crate::mock_server::MockServer;
+    use crate::submit::{Hint, Submissions, Verdict};
+    use aoc_common::answer::Answer;
+    use aoc_common::error::Error;
+    use aoc_common::solution::Part;
+    use std::time::Duration;
+
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  \
+                           Please wait one minute before trying again.</p></article>";
+    const RIGHT: &str = "<article><p>That's the right answer!</p></article>";
+
+    #[test]
+    fn test_wrong_answer_is_never_sent_twice() {
+        let path = std::env::temp_dir().join(format!("aoc_submit_{}.toml", std::process::id()));
+        let mut submissions = Submissions::load(&path).unwrap();
+        // Two responses: any third request would find nobody listening.
+        let server = MockServer::start(vec![(200, TOO_LOW), (200, RIGHT)]);
+        let client = Client::new(&server.url, "abc123");
+
+        let verdict = submit_answer(
+            &client,
+            &mut submissions,
+            2023,
+            4,
+            Part::One,
+            &Answer::from(13),
+            1000,
+        );
+        assert_eq!(
+            verdict.unwrap(),
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+
+        // Refused locally: the same answer, a lower one, or anything during the cooldown.
+        for (answer, now) in [(13, 2000), (12, 2000), (25571, 1030)] {
+            let verdict = submit_answer(
+                &client,
+                &mut submissions,
+                2023,
+                4,
+                Part::One,
+                &Answer::from(answer),
+                now,
+            );
+            assert!(matches!(verdict, Err(Error::Submit(_))));
+        }
+
+        let verdict = submit_answer(
+            &client,
+            &mut submissions,
+            2023,
+            4,
+            Part::One,
+            &Answer::from(25571),
+            1060,
+        );
+        assert_eq!(verdict.unwrap(), Verdict::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].ends_with("level=1&answer=13"));
+        assert!(requests[1].ends_with("level=1&answer=25571"));
+    }
+}
@@ -0,0 +1,52 @@
+use super::{print_summary, summarize, Target};
+use crate::answers::KnownAnswers;
+use crate::calendar;
+use crate::cli::{Format, VerifyArgs};
+use crate::summary::Summary;
+use aoc_common::error::{Error, Result};
+use aoc_common::input::{InputLocator, Source};
+use log::warn;
+
+/// Solve every input that has a confirmed answer and compare.
+pub fn run(year: u16, locator: &InputLocator, args: &VerifyArgs) -> Result<()> {
+    let known = KnownAnswers::load(&locator.answers_file())?;
+    let mut jobs = vec![];
+    for (day, name, parts) in known.entries() {
+        if args.day.is_some_and(|only| only != day) {
+            continue;
+        }
+        let Some(registered) = calendar::find(year, day) else {
+            warn!(
+                "Day {}: answers are known but the day is not registered",
+                day
+            );
+            continue;
+        };
+
+        let target = Target {
+            source: Source::File(locator.named(day, &name)),
+            name: Some(name),
+            parts,
+        };
+        jobs.push((registered, target));
+    }
+
+    let mut summary = Summary::default();
+    summarize(&mut summary, &jobs, &known, args.sequential);
+
+    if summary.rows.is_empty() && args.format == Format::Text {
+        println!(
+            "No confirmed answers in {}, record some with `run --confirm`",
+            locator.answers_file().display()
+        );
+        return Ok(());
+    }
+
+    print_summary(&summary, year, args.format);
+    if summary.has_failures() {
+        return Err(Error::Regression(String::from(
+            "some answers do not match anymore",
+        )));
+    }
+    Ok(())
+}
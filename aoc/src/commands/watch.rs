@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use super::{example_targets, summarize, Target};
+use crate::answers::KnownAnswers;
+use crate::calendar;
+use crate::cli::WatchArgs;
+use crate::summary::{Row, Summary};
+use aoc_common::error::{Error, Result};
+use aoc_common::input::{year_dir, InputLocator, Source};
+use aoc_common::solution::{Day, Part};
+
+/// Solve the day again each time its input, its examples or the known answers change.
+/// When its code changes, rebuild and carry on watching with the new build.
+/// Runs until interrupted.
+pub fn run(year: u16, locator: &InputLocator, args: &WatchArgs) -> Result<()> {
+    let day = calendar::find(year, args.day).ok_or(Error::MissingDay {
+        year,
+        day: args.day,
+    })?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let exe = std::env::current_exe().map_err(|e| Error::io("the running executable", e))?;
+    let mut paths = vec![locator.day_dir(args.day), locator.answers_file()];
+    // Outside a checkout there is no code to rebuild, only the inputs to watch.
+    let src = year_dir(year).ok();
+    if let Some(src) = &src {
+        paths.push(src.join(format!("day_{}", args.day)));
+    }
+    let mut watcher = Watcher::new(paths);
+
+    println!(
+        "Watching {} every {:?}, Ctrl-C to stop",
+        locator.day_dir(args.day).display(),
+        Duration::from_millis(args.interval)
+    );
+    loop {
+        let changed = watcher.poll();
+        let first = watcher.first_poll();
+        let (code, data): (Vec<&PathBuf>, Vec<&PathBuf>) = changed
+            .iter()
+            .partition(|path| path.extension().is_some_and(|ext| ext == "rs"));
+
+        if first || !data.is_empty() {
+            println!();
+            solve(day, locator, &parts);
+        }
+        if let (false, false, Some(src)) = (first, code.is_empty(), &src) {
+            for path in code {
+                println!("{} changed, rebuilding", path.display());
+            }
+            match rebuild(src).status() {
+                Ok(status) if status.success() => restart(&exe),
+                Ok(_) => println!("The build failed, still running the previous one"),
+                Err(err) => println!("error: cannot run cargo: {}", err),
+            }
+        }
+        thread::sleep(Duration::from_millis(args.interval));
+    }
+}
+
+/// `cargo build` of this binary in the workspace of `year_src`, the `src/` of a year crate,
+/// with the profile and features of the running one.
+fn rebuild(year_src: &Path) -> Command {
+    let workspace = year_src.ancestors().nth(2).unwrap_or(year_src);
+    let manifest = workspace.join("aoc").join("Cargo.toml");
+    let mut cargo = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cargo.arg("build").arg("--manifest-path").arg(manifest);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        cargo.args(["--features", "alloc-stats"]);
+    }
+    cargo
+}
+
+/// Hand over to the freshly built binary with the same arguments.
+/// It replaces this process where possible, else its exit is ours.
+fn restart(exe: &Path) -> ! {
+    let mut next = Command::new(exe);
+    next.args(std::env::args_os().skip(1));
+    #[cfg(unix)]
+    let err = std::os::unix::process::CommandExt::exec(&mut next);
+    #[cfg(not(unix))]
+    let err = match next.status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(err) => err,
+    };
+    println!("error: cannot start {}: {}", exe.display(), err);
+    process::exit(1)
+}
+
+/// Solve the examples, then the input if there is one, and print how they compare
+/// with the known answers.
+fn solve(day: &Day, locator: &InputLocator, parts: &[Part]) {
+    let known = match KnownAnswers::load(&locator.answers_file()) {
+        Ok(known) => known,
+        Err(err) => {
+            println!("error: {}", err);
+            return;
+        }
+    };
+
+    let mut targets = example_targets(locator, day.day, parts);
+    let input = locator.input(day.day);
+    if input.exists() {
+        targets.push(Target {
+            source: Source::File(input),
+            name: Some(String::from("input")),
+            parts: parts.to_vec(),
+        });
+    }
+
+    let jobs: Vec<_> = targets.into_iter().map(|target| (day, target)).collect();
+    let mut summary = Summary::default();
+    summarize(&mut summary, &jobs, &known, false);
+    print!("{}", summary.table());
+
+    let examples: Vec<&Row> = summary
+        .rows
+        .iter()
+        .filter(|row| row.input != "input")
+        .collect();
+    let right = examples.iter().filter(|row| row.is_right()).count();
+    match examples.iter().filter(|row| row.is_wrong()).count() {
+        0 if right > 0 => println!("Examples: all {} known answers match", right),
+        0 => println!("Examples: no known answer to check against"),
+        wrong => println!("Examples: {} WRONG, {} right", wrong, right),
+    }
+}
+
+/// The files under some paths, polled for changes by size and modification time.
+struct Watcher {
+    paths: Vec<PathBuf>,
+    seen: Option<BTreeMap<PathBuf, (u64, Option<SystemTime>)>>,
+    first: bool,
+}
+
+impl Watcher {
+    /// Each path is a directory, whose files are watched, or a single file.
+    /// Missing paths are fine, they are picked up once created.
+    fn new(paths: Vec<PathBuf>) -> Self {
+        Watcher {
+            paths,
+            seen: None,
+            first: true,
+        }
+    }
+
+    /// The files created, modified or removed since the previous poll; every file the first time.
+    fn poll(&mut self) -> Vec<PathBuf> {
+        let mut now = BTreeMap::new();
+        for path in &self.paths {
+            match fs::read_dir(path) {
+                Ok(entries) => {
+                    for entry in entries.flatten() {
+                        stat(&entry.path(), &mut now);
+                    }
+                }
+                Err(_) => stat(path, &mut now),
+            }
+        }
+
+        self.first = self.seen.is_none();
+        let before = self.seen.replace(now).unwrap_or_default();
+        let now = self.seen.as_ref().expect("just set");
+        let mut changed: Vec<PathBuf> = now
+            .iter()
+            .filter(|(path, stamp)| before.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .chain(before.keys().filter(|p| !now.contains_key(*p)).cloned())
+            .collect();
+        changed.sort();
+        changed.dedup();
+        changed
+    }
+
+    /// Whether the last poll was the first one, which reports every file.
+    fn first_poll(&self) -> bool {
+        self.first
+    }
+}
+
+fn stat(path: &Path, into: &mut BTreeMap<PathBuf, (u64, Option<SystemTime>)>) {
+    if let Ok(meta) = fs::metadata(path) {
+        if meta.is_file() {
+            into.insert(path.to_path_buf(), (meta.len(), meta.modified().ok()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rebuild, Watcher};
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn test_rebuild_builds_this_crate() {
+        let cargo = rebuild(Path::new("/work/aoc_2023/src"));
+        let args: Vec<_> = cargo
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(args[..2], ["build", "--manifest-path"]);
+        assert_eq!(args[2], "/work/aoc/Cargo.toml");
+        assert_eq!(
+            args.contains(&String::from("--release")),
+            !cfg!(debug_assertions)
+        );
+    }
+
+    #[test]
+    fn test_watcher() {
+        let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input.txt"), "1abc2\n").unwrap();
+        let answers = dir.join("answers.toml");
+        let mut watcher = Watcher::new(vec![dir.join("day_1"), dir.clone(), answers.clone()]);
+
+        assert_eq!(watcher.poll(), vec![dir.join("input.txt")]);
+        assert!(watcher.first_poll());
+        assert!(watcher.poll().is_empty());
+        assert!(!watcher.first_poll());
+
+        fs::write(dir.join("input.txt"), "1abc2\ntreb7uchet\n").unwrap();
+        fs::write(&answers, "").unwrap();
+        assert_eq!(watcher.poll(), vec![answers.clone(), dir.join("input.txt")]);
+
+        fs::create_dir_all(dir.join("day_1")).unwrap();
+        fs::write(dir.join("day_1/example.txt"), "two1nine\n").unwrap();
+        assert_eq!(watcher.poll(), vec![dir.join("day_1/example.txt")]);
+
+        fs::remove_file(dir.join("input.txt")).unwrap();
+        assert_eq!(watcher.poll(), vec![dir.join("input.txt")]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,120 @@
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use aoc_common::error::{Error, Result};
+
+/// Path of the configuration file, instead of `~/.config/aoc/config.toml`.
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+/// Session cookie of adventofcode.com, takes precedence over the configuration file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Where to send requests, mostly to point at a local mock server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Per-user settings:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "http://127.0.0.1:8080"  # optional
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// `$AOC_CONFIG`, then `$XDG_CONFIG_HOME/aoc/config.toml`, then `~/.config/aoc/config.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(CONFIG_ENV).filter(|p| !p.is_empty()) {
+            return Some(PathBuf::from(path));
+        }
+        let config_dir = env::var_os("XDG_CONFIG_HOME")
+            .filter(|p| !p.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config_dir.join("aoc").join("config.toml"))
+    }
+
+    /// Read the file if there is one, then let the environment override it.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let mut config = match path {
+            Some(path) => Config::from_file(path)?,
+            None => Config::default(),
+        };
+        if let Ok(session) = env::var(SESSION_ENV) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_ENV) {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    /// A missing file is an empty configuration.
+    fn from_file(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|e| Error::Format {
+                path: path.display().to_string(),
+                message: e.to_string(),
+            }),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(Error::io(path.display().to_string(), e)),
+        }
+    }
+
+    pub fn session(&self) -> Result<&str> {
+        match self.session.as_deref().map(str::trim) {
+            Some(session) if !session.is_empty() => Ok(session),
+            _ => Err(Error::Session(format!(
+                "no session token: set {} or `session` in the configuration file",
+                SESSION_ENV
+            ))),
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL)
+            .trim_end_matches('/')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+    use std::fs;
+
+    #[test]
+    fn test_from_file() {
+        let path = std::env::temp_dir().join(format!("aoc_config_{}.toml", std::process::id()));
+        fs::write(
+            &path,
+            "session = \" abc123\\n\"\nbase_url = \"http://127.0.0.1:8080/\"\n",
+        )
+        .unwrap();
+
+        let config = Config::from_file(&path).unwrap();
+        assert_eq!(config.session().unwrap(), "abc123");
+        assert_eq!(config.base_url(), "http://127.0.0.1:8080");
+
+        fs::write(&path, "sesion = \"typo\"\n").unwrap();
+        assert!(Config::from_file(&path).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = Config::from_file("/does/not/exist.toml".as_ref()).unwrap();
+        assert!(config.session().is_err());
+        assert_eq!(config.base_url(), "https://adventofcode.com");
+    }
+}
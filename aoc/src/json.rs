@@ -0,0 +1,151 @@
+use crate::summary::{Check, Outcome, Row, Summary};
+use aoc_common::answer::Answer;
+use aoc_common::solution::Part;
+use serde::Serialize;
+use serde_json::Value;
+
+/// One part of one day, as printed by `--format json`, one object per line.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: &'a str,
+    pub input_sha256: Option<&'a str>,
+    /// `solved`, `not_solved` or `failed`.
+    pub status: &'static str,
+    pub answer: Option<Value>,
+    pub expected: Option<Value>,
+    /// `match`, `mismatch` or `unknown`, only for solved parts.
+    pub check: Option<&'static str>,
+    pub parse_ns: Option<u64>,
+    pub time_ns: u64,
+    pub error: Option<&'a str>,
+}
+
+impl<'a> Record<'a> {
+    pub fn new(year: u16, row: &'a Row) -> Self {
+        let mut record = Record {
+            year,
+            day: row.day,
+            part: match row.part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            input: &row.input,
+            input_sha256: row.input_hash.as_deref(),
+            status: "solved",
+            answer: None,
+            expected: None,
+            check: None,
+            parse_ns: row.parse.map(|d| nanos(d.as_nanos())),
+            time_ns: nanos(row.elapsed.as_nanos()),
+            error: None,
+        };
+        match &row.outcome {
+            Outcome::Solved { answer, check } => {
+                record.answer = Some(value(answer));
+                record.check = Some(match check {
+                    Check::Unknown => "unknown",
+                    Check::Match => {
+                        record.expected = Some(value(answer));
+                        "match"
+                    }
+                    Check::Mismatch { expected } => {
+                        record.expected = Some(value(expected));
+                        "mismatch"
+                    }
+                });
+            }
+            Outcome::NotSolved => record.status = "not_solved",
+            Outcome::Failed(message) => {
+                record.status = "failed";
+                record.error = Some(message);
+            }
+        }
+        record
+    }
+}
+
+/// JSON Lines: one record per row, in the order they were added.
+pub fn lines(year: u16, summary: &Summary) -> String {
+    summary
+        .rows
+        .iter()
+        .map(|row| {
+            let record = Record::new(year, row);
+            serde_json::to_string(&record).expect("records only hold plain values") + "\n"
+        })
+        .collect()
+}
+
+/// Numbers stay numbers when JSON readers can hold them, anything else is a string.
+fn value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Signed(v) => {
+            i64::try_from(*v).map_or_else(|_| Value::from(v.to_string()), Value::from)
+        }
+        Answer::Unsigned(v) => {
+            u64::try_from(*v).map_or_else(|_| Value::from(v.to_string()), Value::from)
+        }
+        Answer::Text(s) => Value::from(s.as_str()),
+    }
+}
+
+fn nanos(n: u128) -> u64 {
+    u64::try_from(n).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lines;
+    use crate::summary::Summary;
+    use aoc_2023::day_4::Day4;
+    use aoc_common::answer::Answer;
+    use aoc_common::solution::{Part, Runner};
+    use serde_json::Value;
+
+    #[test]
+    fn test_lines() {
+        let mut summary = Summary::default();
+        let example = include_str!("../../aoc_2023/src/day_4/example.txt");
+        summary.add(
+            4,
+            "example",
+            Some(String::from("abc")),
+            &Part::ALL,
+            Day4.run(example, &Part::ALL),
+            |part| (part == Part::Two).then(|| Answer::from(31)),
+        );
+        summary.add(
+            4,
+            "bad",
+            None,
+            &[Part::One],
+            Day4.run("Card 1 | 2", &[Part::One]),
+            |_| None,
+        );
+
+        let records: Vec<Value> = lines(2023, &summary)
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 3);
+
+        assert_eq!(records[0]["year"], 2023);
+        assert_eq!(records[0]["part"], 1);
+        assert_eq!(records[0]["input_sha256"], "abc");
+        assert_eq!(records[0]["status"], "solved");
+        assert_eq!(records[0]["answer"], 13);
+        assert_eq!(records[0]["check"], "unknown");
+        assert!(records[0]["parse_ns"].is_u64());
+
+        assert_eq!(records[1]["check"], "mismatch");
+        assert_eq!(records[1]["expected"], 31);
+        assert!(records[1]["parse_ns"].is_null());
+
+        assert_eq!(records[2]["status"], "failed");
+        assert!(records[2]["answer"].is_null());
+        assert!(records[2]["error"].is_string());
+    }
+}
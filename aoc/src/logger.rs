@@ -0,0 +1,160 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::Mutex;
+
+/// Writes every enabled record to stderr, so stdout only carries the answers.
+struct StderrLogger;
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// While days run side by side, their records with the module they came from.
+static HELD: Mutex<Option<Vec<(String, String)>>> = Mutex::new(None);
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format_record(record);
+        match HELD.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+            Some(held) => held.push((record.target().to_string(), line)),
+            None => eprintln!("{}", line),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn format_record(record: &Record) -> String {
+    match record.level() {
+        // Errors and warnings are meant for the user, the rest for whoever debugs a day.
+        Level::Error | Level::Warn => format!(
+            "{}: {}",
+            record.level().as_str().to_lowercase(),
+            record.args()
+        ),
+        level => format!("[{:<5} {}] {}", level, record.target(), record.args()),
+    }
+}
+
+/// Warnings by default, each `-v` shows one more level and each `-q` one less.
+pub fn level_filter(verbose: u8, quiet: u8) -> LevelFilter {
+    const LEVELS: [LevelFilter; 6] = [
+        LevelFilter::Off,
+        LevelFilter::Error,
+        LevelFilter::Warn,
+        LevelFilter::Info,
+        LevelFilter::Debug,
+        LevelFilter::Trace,
+    ];
+    let index = (2 + verbose as i32 - quiet as i32).clamp(0, LEVELS.len() as i32 - 1);
+    LEVELS[index as usize]
+}
+
+/// Keep records back instead of writing them, until [`release`].
+pub fn hold() {
+    *HELD.lock().unwrap_or_else(|e| e.into_inner()) = Some(vec![]);
+}
+
+/// Write records as they come again, and hand over the ones kept back.
+pub fn release() -> Held {
+    Held(
+        HELD.lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .unwrap_or_default(),
+    )
+}
+
+/// Records kept back while days ran side by side, to print one day after the other.
+pub struct Held(Vec<(String, String)>);
+
+impl Held {
+    /// The records of `module` and its submodules, in the order they came.
+    fn take(&mut self, module: &str) -> Vec<String> {
+        let (taken, kept) = self.0.drain(..).partition(|(target, _)| {
+            target == module
+                || target
+                    .strip_prefix(module)
+                    .is_some_and(|t| t.starts_with("::"))
+        });
+        self.0 = kept;
+        taken.into_iter().map(|(_, line)| line).collect()
+    }
+
+    pub fn print(&mut self, module: &str) {
+        for line in self.take(module) {
+            eprintln!("{}", line);
+        }
+    }
+
+    /// Whatever did not come from a day.
+    pub fn print_rest(self) {
+        for (_, line) in self.0 {
+            eprintln!("{}", line);
+        }
+    }
+}
+
+pub fn init(level: LevelFilter) {
+    // Only fails if a logger is already set, in which case it keeps working.
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_record, level_filter, Held};
+    use log::{Level, LevelFilter, Record};
+
+    #[test]
+    fn test_level_filter() {
+        assert_eq!(level_filter(0, 0), LevelFilter::Warn);
+        assert_eq!(level_filter(1, 0), LevelFilter::Info);
+        assert_eq!(level_filter(3, 0), LevelFilter::Trace);
+        assert_eq!(level_filter(9, 0), LevelFilter::Trace);
+        assert_eq!(level_filter(0, 1), LevelFilter::Error);
+        assert_eq!(level_filter(0, 5), LevelFilter::Off);
+    }
+
+    #[test]
+    fn test_format_record() {
+        let error = Record::builder()
+            .level(Level::Error)
+            .target("aoc::summary")
+            .args(format_args!("Day 4: boom"))
+            .build();
+        assert_eq!(format_record(&error), "error: Day 4: boom");
+
+        let debug = Record::builder()
+            .level(Level::Debug)
+            .target("aoc_2023::day_4")
+            .args(format_args!("sum: 13"))
+            .build();
+        assert_eq!(format_record(&debug), "[DEBUG aoc_2023::day_4] sum: 13");
+    }
+
+    #[test]
+    fn test_held_by_module() {
+        let mut held = Held(
+            [
+                ("aoc_2023::day_1", "a"),
+                ("aoc_2023::day_12", "b"),
+                ("aoc_2023::day_1::words", "c"),
+                ("aoc::summary", "d"),
+                ("aoc_2023::day_1", "e"),
+            ]
+            .iter()
+            .map(|&(target, line)| (target.to_string(), line.to_string()))
+            .collect(),
+        );
+        assert_eq!(held.take("aoc_2023::day_1"), vec!["a", "c", "e"]);
+        assert_eq!(held.take("aoc_2023::day_1"), Vec::<String>::new());
+        assert_eq!(held.take("aoc_2023::day_12"), vec!["b"]);
+        assert_eq!(held.0.len(), 1);
+    }
+}
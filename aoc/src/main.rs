@@ -0,0 +1,69 @@
+mod answers;
+mod bench;
+mod calendar;
+mod cli;
+mod client;
+mod commands;
+mod config;
+mod json;
+mod logger;
+#[cfg(test)]
+mod mock_server;
+mod puzzle;
+mod scaffold;
+mod submit;
+mod summary;
+mod table;
+
+use aoc_common::input::InputLocator;
+use clap::Parser;
+use cli::{Cli, Command};
+use config::Config;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: aoc_common::alloc::Counting = aoc_common::alloc::Counting;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logger::init(logger::level_filter(cli.verbose, cli.quiet));
+
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> aoc_common::Result<()> {
+    let year = cli.year;
+    // `new` writes code, not inputs: it has no input directory to find.
+    let locator = match &cli.command {
+        Command::New(args) => return commands::new::run(year, args),
+        _ => InputLocator::resolve(cli.input_dir.as_deref(), year)?,
+    };
+
+    match cli.command {
+        Command::Run(args) => commands::run::run(year, &locator, &args),
+        Command::Verify(args) => commands::verify::run(year, &locator, &args),
+        Command::Fetch(args) => load_config(cli.config)
+            .and_then(|config| commands::fetch::run(year, &locator, &config, &args)),
+        Command::Submit(args) => load_config(cli.config)
+            .and_then(|config| commands::submit::run(year, &locator, &config, &args)),
+        Command::New(_) => unreachable!("handled above"),
+        Command::Examples(args) => {
+            commands::examples::run(year, &locator, || load_config(cli.config), &args)
+        }
+        Command::Watch(args) => commands::watch::run(year, &locator, &args),
+        Command::Bench(args) => commands::bench::run(year, &locator, &args),
+    }
+}
+
+/// Only the commands talking to the website need it, so a broken file does not stop `run`.
+fn load_config(path: Option<PathBuf>) -> aoc_common::Result<Config> {
+    Config::load(path.or_else(Config::default_path).as_deref())
+}
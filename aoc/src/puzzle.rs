@@ -0,0 +1,149 @@
+//! Reads the examples and their answers out of a puzzle page.
+
+use aoc_common::answer::Answer;
+use aoc_common::solution::Part;
+
+/// The example given for one part of the puzzle, with the answer it should give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub answer: Option<Answer>,
+}
+
+/// One example per part described on the page: part 2 only shows once part 1 is solved.
+///
+/// The example of a part is the first `<pre><code>` block of its `<article>`, or the one of
+/// part 1 when part 2 has none. Its answer is the last emphasized code of the article,
+/// `<code><em>142</em></code>`, which is how the puzzles end their walkthrough.
+pub fn examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+
+    for (article, part) in articles(html).into_iter().zip(Part::ALL) {
+        let input = match blocks(article, "<pre><code>", "</code></pre>").first() {
+            Some(block) => text(block),
+            None => match examples.first() {
+                Some(example) => example.input.clone(),
+                None => continue,
+            },
+        };
+        let answer = blocks(article, "<code><em>", "</em></code>")
+            .into_iter()
+            .chain(blocks(article, "<em><code>", "</code></em>"))
+            // Both slices point into the page: the highest address is the last one.
+            .max_by_key(|block| block.as_ptr())
+            .and_then(|block| text(block).parse().ok());
+
+        examples.push(Example {
+            part,
+            input,
+            answer,
+        });
+    }
+    examples
+}
+
+/// The puzzle descriptions, `<article class="day-desc">`, in order.
+fn articles(html: &str) -> Vec<&str> {
+    blocks(html, "<article", "</article>")
+}
+
+/// Everything between each `open` and the following `close`.
+fn blocks<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+        found.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+    found
+}
+
+/// `html` without its tags and with its entities decoded; whitespace is kept.
+pub fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{examples, text, Example};
+    use aoc_common::answer::Answer;
+    use aoc_common::solution::Part;
+
+    // Trimmed down from the page of day 1.
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+<p>Consider your entire calibration document. <em>What is the sum of all of the calibration values?</em></p>
+</article>
+<p>Your puzzle answer was <code>53515</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+eightwothree
+</code></pre>
+<p>Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            examples(PAGE),
+            vec![
+                Example {
+                    part: Part::One,
+                    input: String::from("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"),
+                    answer: Some(Answer::from(142)),
+                },
+                Example {
+                    part: Part::Two,
+                    input: String::from("two1nine\neightwothree\n"),
+                    answer: Some(Answer::from(281)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part_two_reuses_the_example() {
+        let page = r#"<article><pre><code>a &lt; b
+</code></pre><p><em><code>7</code></em></p></article>
+<article><p>Now the answer is <code><em>X&amp;Y</em></code>.</p></article>"#;
+        let found = examples(page);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].input, "a < b\n");
+        assert_eq!(found[0].answer, Some(Answer::from(7)));
+        assert_eq!(found[1].input, "a < b\n");
+        assert_eq!(found[1].answer, Some(Answer::from("X&Y")));
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(text("<em>1</em>&lt;<b>2</b> &amp;&amp; 3"), "1<2 && 3");
+        assert!(examples("<p>Please log in.</p>").is_empty());
+    }
+}
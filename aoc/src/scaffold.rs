@@ -0,0 +1,497 @@
+//! Generates the module of a new day and registers it, so that it runs right away,
+//! then the tests of its examples once they are known.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::puzzle::Example;
+use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+
+/// `{day}` is replaced by the day number.
+const MODULE_TEMPLATE: &str = r#"use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+use aoc_common::solution::{Part, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, _lines: &Vec<String>) -> Result<Answer> {
+        Err(Error::NotSolved(Part::One))
+    }
+
+    fn part2(&self, _lines: &Vec<String>) -> Result<Answer> {
+        Err(Error::NotSolved(Part::Two))
+    }
+}
+"#;
+
+/// Opens the test module owned by `examples`: everything from here to the end is rewritten.
+const EXAMPLES_MARKER: &str = "#[cfg(test)]\nmod examples {";
+
+/// Stands in for real example tests until `examples` generates them.
+const PLACEHOLDER_TESTS: &str = r#"
+#[cfg(test)]
+mod tests {
+    use super::Day{day};
+    use aoc_common::answer::Answer;
+    use aoc_common::solution::Solution;
+
+    #[test]
+    #[ignore = "run `examples --day {day}`, or paste the example and its answer here"]
+    fn test_example() {
+        let input = Day{day}.parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Day{day}.part1(&input).unwrap(), Answer::from(0));
+    }
+}
+"#;
+
+/// Write `day_N/mod.rs` and an empty `example.txt` under `src`, then declare the module in
+/// `lib.rs` and add it to the registry. Nothing is written if the day already exists.
+/// Returns the files created or changed.
+pub fn scaffold(src: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    let day_dir = src.join(format!("day_{}", day));
+    let module = day_dir.join("mod.rs");
+    let example = day_dir.join("example.txt");
+    let lib = src.join("lib.rs");
+    let registry = src.join("registry.rs");
+
+    if module.exists() {
+        return Err(Error::Scaffold(format!(
+            "{} already exists, not overwriting it",
+            module.display()
+        )));
+    }
+    // Both files are rewritten only once both edits are known to work.
+    let new_lib = declare_module(&read(&lib)?, day).map_err(|e| in_path(&lib, e))?;
+    let new_registry =
+        register_day(&read(&registry)?, year, day).map_err(|e| in_path(&registry, e))?;
+
+    let mut written = vec![module.clone()];
+    fs::create_dir_all(&day_dir).map_err(|e| Error::io(day_dir.display().to_string(), e))?;
+    let template = format!("{}{}", MODULE_TEMPLATE, PLACEHOLDER_TESTS);
+    write(&module, &template.replace("{day}", &day.to_string()))?;
+    // The input directory may be the same, with an example already fetched.
+    if !example.exists() {
+        write(&example, "")?;
+        written.push(example);
+    }
+    write(&lib, &new_lib)?;
+    write(&registry, &new_registry)?;
+    written.extend([lib, registry]);
+    Ok(written)
+}
+
+/// The file each example goes to: `example.txt`, or `example_2.txt` when part 2 has its own.
+pub fn example_name(examples: &[Example], example: &Example) -> String {
+    match examples.first() {
+        Some(first) if first.input != example.input => format!("example_{}", example.part),
+        _ => String::from("example"),
+    }
+}
+
+/// Write the examples next to the module of `day`, and replace its example tests with one
+/// per known answer. Example files that differ are only overwritten with `force`.
+/// Returns the files created or changed.
+pub fn add_examples(
+    src: &Path,
+    year: u16,
+    day: u8,
+    examples: &[Example],
+    force: bool,
+) -> Result<Vec<PathBuf>> {
+    let day_dir = src.join(format!("day_{}", day));
+    let module = day_dir.join("mod.rs");
+    if !module.exists() {
+        return Err(Error::Scaffold(format!(
+            "{} does not exist, generate it first with `new --day {}`",
+            module.display(),
+            day
+        )));
+    }
+    let solver = solver_of(&read(&src.join("registry.rs"))?, year, day)
+        .unwrap_or_else(|| format!("Day{}", day));
+
+    let mut files: Vec<(PathBuf, &str)> = vec![];
+    for example in examples {
+        let path = day_dir.join(format!("{}.txt", example_name(examples, example)));
+        if files.iter().any(|(p, _)| *p == path) {
+            continue;
+        }
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == example.input => continue,
+            Ok(existing) if !existing.is_empty() && !force => {
+                return Err(Error::Scaffold(format!(
+                    "{} differs from the example of the page, --force overwrites it",
+                    path.display()
+                )));
+            }
+            _ => files.push((path, &example.input)),
+        }
+    }
+
+    let source = read(&module)?;
+    let source = with_example_tests(&source, day, &solver, examples);
+
+    let mut written = vec![];
+    for (path, input) in files {
+        write(&path, input)?;
+        written.push(path);
+    }
+    write(&module, &source)?;
+    written.push(module);
+    Ok(written)
+}
+
+/// The expression the registry solves `day` with, such as `Day2::new(12, 13, 14)`.
+fn solver_of(registry: &str, year: u16, day: u8) -> Option<String> {
+    let line = registry
+        .lines()
+        .find(|line| entry_key(line) == Some((year, day)))?;
+    let path = line.split_once('&')?.1.trim_end().strip_suffix("),")?;
+    let solver = path.strip_prefix(&format!("day_{}::", day))?;
+    Some(solver.to_string())
+}
+
+/// `source` without the placeholder test of the template nor the previous example tests,
+/// followed by a test of each example with an answer.
+fn with_example_tests(source: &str, day: u8, solver: &str, examples: &[Example]) -> String {
+    let placeholder = PLACEHOLDER_TESTS.replace("{day}", &day.to_string());
+    let source = source.replacen(&placeholder, "", 1);
+    let source = match source.find(EXAMPLES_MARKER) {
+        Some(start) => source[..start].trim_end(),
+        None => source.trim_end(),
+    };
+    let tested: Vec<&Example> = examples.iter().filter(|e| e.answer.is_some()).collect();
+    if tested.is_empty() {
+        return format!("{}\n", source);
+    }
+
+    let mut out = format!(
+        "{}\n\n{}\n    //! Generated by `examples --day {}` from the puzzle page.\n\n",
+        source, EXAMPLES_MARKER, day
+    );
+    let (name, _) = solver.split_once('(').unwrap_or((solver, ""));
+    let (name, _) = name.rsplit_once("::").unwrap_or((name, ""));
+    out.push_str(&format!("    use super::{};\n", name));
+    out.push_str("    use aoc_common::answer::Answer;\n    use aoc_common::solution::Solution;\n");
+
+    for example in tested {
+        let answer = example
+            .answer
+            .as_ref()
+            .map(answer_literal)
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "\n    #[test]\n    fn test_example_part{part}() {{\n        \
+             let day = {solver};\n        \
+             let input = day.parse(include_str!(\"{file}.txt\")).unwrap();\n        \
+             assert_eq!(day.part{part}(&input).unwrap(), Answer::from({answer}));\n    }}\n",
+            part = example.part,
+            solver = solver,
+            file = example_name(examples, example),
+            answer = answer,
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// The answer as a Rust literal `Answer::from` takes; integers only get a suffix past `i32`.
+fn answer_literal(answer: &Answer) -> String {
+    match answer {
+        Answer::Signed(v) if i32::try_from(*v).is_ok() => v.to_string(),
+        Answer::Unsigned(v) if i32::try_from(*v).is_ok() => v.to_string(),
+        Answer::Signed(v) => format!("{}_i128", v),
+        Answer::Unsigned(v) => format!("{}_u128", v),
+        Answer::Text(text) => format!("{:?}", text),
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| Error::io(path.display().to_string(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content).map_err(|e| Error::io(path.display().to_string(), e))
+}
+
+fn in_path(path: &Path, message: String) -> Error {
+    Error::Scaffold(format!("{}: {}", path.display(), message))
+}
+
+/// Add `pub mod day_N;` among the other day modules, in the order rustfmt keeps them.
+fn declare_module(lib: &str, day: u8) -> std::result::Result<String, String> {
+    let name = format!("day_{}", day);
+    let declared = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|l| l.strip_suffix(';'))
+            .filter(|module| module.starts_with("day_"))
+            .map(String::from)
+    };
+
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let days: Vec<(usize, String)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| declared(line).map(|module| (i, module)))
+        .collect();
+    if days.iter().any(|(_, module)| *module == name) {
+        return Err(format!("{} is already declared", name));
+    }
+    let position = match days.iter().find(|(_, module)| *module > name) {
+        Some((i, _)) => *i,
+        None => days.last().ok_or("no day module to declare it next to")?.0 + 1,
+    };
+
+    let declaration = format!("pub mod {};", name);
+    lines.insert(position, &declaration);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Import the module and add its line to `DAYS`, keeping the days in order.
+fn register_day(registry: &str, year: u16, day: u8) -> std::result::Result<String, String> {
+    let name = format!("day_{}", day);
+    let (before, rest) = registry
+        .split_once("use crate::{")
+        .ok_or("no `use crate::{...}` importing the days")?;
+    let (imports, after) = rest.split_once("};").ok_or("unterminated `use crate::{`")?;
+
+    let mut modules: Vec<&str> = imports
+        .split(',')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .collect();
+    if modules.contains(&name.as_str()) {
+        return Err(format!("{} is already registered", name));
+    }
+    modules.push(&name);
+    modules.sort_unstable();
+
+    let entry = format!("    Day::new({}, {}, &{}::Day{}),", year, day, name, day);
+    let mut lines: Vec<&str> = after.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("pub const DAYS"))
+        .ok_or("no `DAYS` list")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l.trim() == "];")
+            .ok_or("unterminated `DAYS` list")?;
+    let position = (start + 1..end)
+        .find(|&i| entry_key(lines[i]).is_some_and(|key| key > (year, day)))
+        .unwrap_or(end);
+    lines.insert(position, &entry);
+
+    Ok(format!(
+        "{}{}{}\n",
+        before,
+        use_list(&modules),
+        lines.join("\n")
+    ))
+}
+
+/// `(year, day)` of a `Day::new(year, day, ...)` line.
+fn entry_key(line: &str) -> Option<(u16, u8)> {
+    let mut args = line.trim().strip_prefix("Day::new(")?.split(',');
+    let year = args.next()?.trim().parse().ok()?;
+    let day = args.next()?.trim().parse().ok()?;
+    Some((year, day))
+}
+
+/// `use crate::{...};` laid out as rustfmt would, on one line or wrapped at 100 columns.
+fn use_list(modules: &[&str]) -> String {
+    let one_line = format!("use crate::{{{}}};", modules.join(", "));
+    if one_line.len() <= 100 {
+        return one_line;
+    }
+
+    let mut out = String::from("use crate::{\n");
+    let mut line = String::from("   ");
+    for module in modules {
+        if line.len() + module.len() + 2 > 100 {
+            out.push_str(line.trim_end());
+            out.push('\n');
+            line = String::from("   ");
+        }
+        line.push(' ');
+        line.push_str(module);
+        line.push(',');
+    }
+    out.push_str(&line);
+    out.push_str("\n};");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        add_examples, answer_literal, declare_module, register_day, scaffold, solver_of, use_list,
+    };
+    use crate::puzzle::Example;
+    use aoc_common::answer::Answer;
+    use aoc_common::error::Error;
+    use aoc_common::solution::Part;
+    use std::fs;
+
+    const LIB: &str =
+        "pub mod answer;\npub mod day_1;\npub mod day_2;\npub mod error;\n\npub fn solve() {}\n";
+    const REGISTRY: &str = "use aoc_common::Day;
+use crate::{day_1, day_2};
+
+pub const DAYS: &[Day] = &[
+    Day::new(2023, 1, &day_1::Day1),
+    Day::new(2023, 2, &day_2::Day2::new(12, 13, 14)),
+];
+
+pub fn find() {}
+";
+
+    #[test]
+    fn test_declare_module() {
+        let lib = declare_module(LIB, 5).unwrap();
+        assert!(lib.starts_with(
+            "pub mod answer;\npub mod day_1;\npub mod day_2;\npub mod day_5;\npub mod error;\n"
+        ));
+        // rustfmt orders modules as strings: day_10 sits between day_1 and day_2.
+        let lib = declare_module(&lib, 10).unwrap();
+        assert!(lib.contains("pub mod day_1;\npub mod day_10;\npub mod day_2;\n"));
+        assert!(declare_module(LIB, 2).is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registry = register_day(REGISTRY, 2023, 5).unwrap();
+        assert!(registry.contains("use crate::{day_1, day_2, day_5};\n"));
+        assert!(registry.contains(
+            "    Day::new(2023, 2, &day_2::Day2::new(12, 13, 14)),\n    Day::new(2023, 5, &day_5::Day5),\n];\n\npub fn find() {}\n"
+        ));
+        assert!(registry.starts_with("use aoc_common::Day;\n"));
+        assert!(register_day(REGISTRY, 2023, 1).is_err());
+    }
+
+    #[test]
+    fn test_use_list_wraps() {
+        let names: Vec<String> = (1..=25).map(|d| format!("day_{}", d)).collect();
+        let mut modules: Vec<&str> = names.iter().map(String::as_str).collect();
+        modules.sort_unstable();
+        let list = use_list(&modules);
+        assert!(list.starts_with("use crate::{\n    day_1, day_10,"));
+        assert!(list.ends_with(" day_9,\n};"));
+        assert!(list.lines().all(|l| l.len() <= 100));
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let src = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), LIB).unwrap();
+        fs::write(src.join("registry.rs"), REGISTRY).unwrap();
+
+        let written = scaffold(&src, 2023, 3).unwrap();
+        assert_eq!(written.len(), 4);
+        let module = fs::read_to_string(src.join("day_3/mod.rs")).unwrap();
+        assert!(module.contains("pub struct Day3;"));
+        assert!(module.contains("impl Solution for Day3 {"));
+        assert!(src.join("day_3/example.txt").exists());
+        assert!(fs::read_to_string(src.join("lib.rs"))
+            .unwrap()
+            .contains("pub mod day_3;"));
+
+        let registry = fs::read_to_string(src.join("registry.rs")).unwrap();
+        assert!(matches!(scaffold(&src, 2023, 3), Err(Error::Scaffold(_))));
+        assert_eq!(
+            fs::read_to_string(src.join("registry.rs")).unwrap(),
+            registry
+        );
+
+        fs::remove_dir_all(&src).unwrap();
+    }
+
+    #[test]
+    fn test_solver_of() {
+        assert_eq!(solver_of(REGISTRY, 2023, 1).as_deref(), Some("Day1"));
+        assert_eq!(
+            solver_of(REGISTRY, 2023, 2).as_deref(),
+            Some("Day2::new(12, 13, 14)")
+        );
+        assert_eq!(solver_of(REGISTRY, 2023, 3), None);
+    }
+
+    #[test]
+    fn test_answer_literal() {
+        assert_eq!(answer_literal(&Answer::from(142)), "142");
+        assert_eq!(answer_literal(&Answer::from(-3)), "-3");
+        assert_eq!(
+            answer_literal(&Answer::from(8_805_731_000_u64)),
+            "8805731000_u128"
+        );
+        assert_eq!(answer_literal(&Answer::from("ABC")), "\"ABC\"");
+    }
+
+    #[test]
+    fn test_add_examples() {
+        let src = std::env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), LIB).unwrap();
+        fs::write(src.join("registry.rs"), REGISTRY).unwrap();
+        scaffold(&src, 2023, 3).unwrap();
+
+        let examples = vec![
+            Example {
+                part: Part::One,
+                input: String::from("1abc2\n"),
+                answer: Some(Answer::from(12)),
+            },
+            Example {
+                part: Part::Two,
+                input: String::from("two1nine\n"),
+                answer: Some(Answer::from(29)),
+            },
+        ];
+        let written = add_examples(&src, 2023, 3, &examples, false).unwrap();
+        assert_eq!(written.len(), 3);
+        assert_eq!(
+            fs::read_to_string(src.join("day_3/example_2.txt")).unwrap(),
+            "two1nine\n"
+        );
+
+        let module = fs::read_to_string(src.join("day_3/mod.rs")).unwrap();
+        assert!(!module.contains("#[ignore"));
+        assert!(module.contains("mod examples {"));
+        assert!(module.contains(
+            "let day = Day3;\n        \
+             let input = day.parse(include_str!(\"example_2.txt\")).unwrap();\n        \
+             assert_eq!(day.part2(&input).unwrap(), Answer::from(29));"
+        ));
+
+        // Running it again only rewrites the generated tests.
+        add_examples(&src, 2023, 3, &examples, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(src.join("day_3/mod.rs")).unwrap(),
+            module
+        );
+
+        let mut changed = examples.clone();
+        changed[0].input = String::from("9\n");
+        assert!(matches!(
+            add_examples(&src, 2023, 3, &changed, false),
+            Err(Error::Scaffold(_))
+        ));
+        add_examples(&src, 2023, 3, &changed, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(src.join("day_3/example.txt")).unwrap(),
+            "9\n"
+        );
+
+        assert!(add_examples(&src, 2023, 4, &examples, false).is_err());
+        fs::remove_dir_all(&src).unwrap();
+    }
+}
@@ -0,0 +1,395 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::puzzle;
+use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+use aoc_common::solution::Part;
+
+/// What the website thought of an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        /// How long the website wants us to wait before the next guess.
+        wait: Option<Duration>,
+    },
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part is already solved, or part 1 is not yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Read the verdict out of the `<article>` of the answer page.
+pub fn parse_verdict(html: &str) -> Verdict {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        return Verdict::Correct;
+    }
+    if text.contains("You gave an answer too recently") {
+        let wait = find_wait(&text).unwrap_or(Duration::from_secs(60));
+        return Verdict::TooSoon { wait };
+    }
+    if text.contains("That's not the right answer") {
+        let hint = if text.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        return Verdict::Wrong {
+            hint,
+            wait: find_wait(&text),
+        };
+    }
+    if text.contains("You don't seem to be solving the right level") {
+        return Verdict::WrongLevel;
+    }
+    Verdict::Unknown(text)
+}
+
+/// The text of the first `<article>`, or of the whole page, without its tags.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let text = puzzle::text(article);
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// "You have 1m 23s left to wait", "Please wait one minute", "wait 5 minutes".
+fn find_wait(text: &str) -> Option<Duration> {
+    if let Some(end) = text.find(" left to wait") {
+        let start = text[..end].rfind("You have ")? + "You have ".len();
+        let mut seconds = 0;
+        for token in text[start..end].split_whitespace() {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            seconds += match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let after = &text[text.find("wait ")? + "wait ".len()..];
+    let mut words = after.split_whitespace();
+    let amount = match words.next()? {
+        "one" | "a" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        n => n.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(amount * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(amount)),
+        _ => None,
+    }
+}
+
+/// Everything submitted so far, kept next to the inputs so no wrong answer is sent twice.
+/// Guesses are keyed by year as well, in case two years ever share the file.
+///
+/// ```toml
+/// next_allowed = 1701700000
+///
+/// [2023.day_4.part1]
+/// wrong = ["26000", "25000"]
+/// too_high = "26000"
+/// too_low = "25000"
+/// ```
+#[derive(Debug)]
+pub struct Submissions {
+    path: PathBuf,
+    log: Log,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Log {
+    /// Unix time before which the website will not take another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next_allowed: Option<u64>,
+    #[serde(flatten)]
+    years: BTreeMap<String, BTreeMap<String, BTreeMap<String, Guesses>>>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct Guesses {
+    #[serde(default)]
+    wrong: Vec<String>,
+    /// Lowest answer known to be too high.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    too_high: Option<String>,
+    /// Highest answer known to be too low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    too_low: Option<String>,
+}
+
+fn keys(year: u16, day: u8, part: Part) -> (String, String, String) {
+    (
+        year.to_string(),
+        format!("day_{}", day),
+        format!("part{}", part),
+    )
+}
+
+fn as_number(answer: &Answer) -> Option<i128> {
+    match answer {
+        Answer::Signed(v) => Some(*v),
+        Answer::Unsigned(v) => i128::try_from(*v).ok(),
+        Answer::Text(_) => None,
+    }
+}
+
+impl Submissions {
+    /// A missing file means nothing was submitted yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let log = match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|e| Error::Format {
+                path: path.display().to_string(),
+                message: e.to_string(),
+            })?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Log::default(),
+            Err(e) => return Err(Error::io(path.display().to_string(), e)),
+        };
+        Ok(Submissions {
+            path: path.to_path_buf(),
+            log,
+        })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let content = toml::to_string_pretty(&self.log).map_err(|e| Error::Format {
+            path: self.path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        fs::write(&self.path, content).map_err(|e| Error::io(self.path.display().to_string(), e))
+    }
+
+    /// Why `answer` must not be sent, if it was already refused or is out of known bounds.
+    pub fn refusal(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Option<String> {
+        let (year_key, day_key, part_key) = keys(year, day, part);
+        let guesses = self
+            .log
+            .years
+            .get(&year_key)?
+            .get(&day_key)?
+            .get(&part_key)?;
+
+        if guesses
+            .wrong
+            .iter()
+            .any(|wrong| wrong.parse::<Answer>().is_ok_and(|w| w == *answer))
+        {
+            return Some(format!("{} was already refused", answer));
+        }
+
+        let value = as_number(answer)?;
+        let bound = |b: &Option<String>| {
+            b.as_ref()
+                .and_then(|b| b.parse::<Answer>().ok())
+                .and_then(|b| as_number(&b))
+        };
+        if let Some(too_high) = bound(&guesses.too_high).filter(|&high| value >= high) {
+            return Some(format!("{} is too high, {} already was", answer, too_high));
+        }
+        if let Some(too_low) = bound(&guesses.too_low).filter(|&low| value <= low) {
+            return Some(format!("{} is too low, {} already was", answer, too_low));
+        }
+        None
+    }
+
+    /// How long to wait before the website takes another answer, if at all.
+    pub fn cooldown(&self, now: u64) -> Option<Duration> {
+        self.log
+            .next_allowed
+            .filter(|&next| next > now)
+            .map(|next| Duration::from_secs(next - now))
+    }
+
+    /// Remember what the website said about `answer`, at unix time `now`.
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        verdict: &Verdict,
+        now: u64,
+    ) {
+        let wait = match verdict {
+            Verdict::Wrong { wait, .. } => *wait,
+            Verdict::TooSoon { wait } => Some(*wait),
+            _ => None,
+        };
+        if let Some(wait) = wait {
+            self.log.next_allowed = Some(now + wait.as_secs());
+        }
+
+        let Verdict::Wrong { hint, .. } = verdict else {
+            return;
+        };
+        let (year_key, day_key, part_key) = keys(year, day, part);
+        let guesses = self
+            .log
+            .years
+            .entry(year_key)
+            .or_default()
+            .entry(day_key)
+            .or_default()
+            .entry(part_key)
+            .or_default();
+        guesses.wrong.push(answer.to_string());
+
+        let Some(value) = as_number(answer) else {
+            return;
+        };
+        let current = |b: &Option<String>| {
+            b.as_ref()
+                .and_then(|b| b.parse::<Answer>().ok())
+                .and_then(|b| as_number(&b))
+        };
+        match hint {
+            Some(Hint::TooHigh) if current(&guesses.too_high).is_none_or(|high| value < high) => {
+                guesses.too_high = Some(answer.to_string());
+            }
+            Some(Hint::TooLow) if current(&guesses.too_low).is_none_or(|low| value > low) => {
+                guesses.too_low = Some(answer.to_string());
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_wait, parse_verdict, Hint, Submissions, Verdict};
+    use aoc_common::answer::Answer;
+    use aoc_common::solution::Part;
+    use std::fs;
+    use std::time::Duration;
+
+    const RIGHT: &str = r#"<main><article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to restoring snow operations. <a href="/2023/day/4#part2">[Continue to Part Two]</a></p></article></main>"#;
+    const TOO_HIGH: &str = r#"<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2023/about">about page</a>, or you can ask for hints on the <a href="https://www.reddit.com/r/adventofcode/" target="_blank">subreddit</a>.  Please wait one minute before trying again. <a href="/2023/day/4">[Return to Day 4]</a></p></article></main>"#;
+    const TOO_SOON: &str = r#"<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href="/2023/day/4">[Return to Day 4]</a></p></article></main>"#;
+    const WRONG_LEVEL: &str = r#"<main><article><p>You don't seem to be solving the right level.  Did you already complete it? <a href="/2023/day/4">[Return to Day 4]</a></p></article></main>"#;
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(parse_verdict(RIGHT), Verdict::Correct);
+        assert_eq!(
+            parse_verdict(TOO_HIGH),
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            parse_verdict(TOO_SOON),
+            Verdict::TooSoon {
+                wait: Duration::from_secs(83)
+            }
+        );
+        assert_eq!(parse_verdict(WRONG_LEVEL), Verdict::WrongLevel);
+        assert!(
+            matches!(parse_verdict("<p>Maintenance</p>"), Verdict::Unknown(text) if text == "Maintenance")
+        );
+    }
+
+    #[test]
+    fn test_find_wait() {
+        assert_eq!(
+            find_wait("You have 34s left to wait."),
+            Some(Duration::from_secs(34))
+        );
+        assert_eq!(
+            find_wait("Please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(find_wait("That's not the right answer."), None);
+    }
+
+    #[test]
+    fn test_record_and_refuse() {
+        let path =
+            std::env::temp_dir().join(format!("aoc_submissions_{}.toml", std::process::id()));
+        let mut submissions = Submissions::load(&path).unwrap();
+        let too_high = Verdict::Wrong {
+            hint: Some(Hint::TooHigh),
+            wait: Some(Duration::from_secs(60)),
+        };
+        let too_low = Verdict::Wrong {
+            hint: Some(Hint::TooLow),
+            wait: None,
+        };
+
+        submissions.record(2023, 4, Part::One, &Answer::from(26000), &too_high, 1000);
+        submissions.record(2023, 4, Part::One, &Answer::from(25000), &too_low, 1010);
+        submissions.record(
+            2023,
+            4,
+            Part::One,
+            &Answer::from(25500),
+            &Verdict::Correct,
+            1100,
+        );
+        submissions.save().unwrap();
+
+        let submissions = Submissions::load(&path).unwrap();
+        assert_eq!(submissions.cooldown(1030), Some(Duration::from_secs(30)));
+        assert_eq!(submissions.cooldown(1060), None);
+
+        assert!(submissions
+            .refusal(2023, 4, Part::One, &Answer::from(26000))
+            .is_some());
+        assert!(submissions
+            .refusal(2023, 4, Part::One, &Answer::from(30000))
+            .is_some());
+        assert!(submissions
+            .refusal(2023, 4, Part::One, &Answer::from(24000))
+            .is_some());
+        assert_eq!(
+            submissions.refusal(2023, 4, Part::One, &Answer::from(25571)),
+            None
+        );
+        assert_eq!(
+            submissions.refusal(2023, 4, Part::Two, &Answer::from(26000)),
+            None
+        );
+        assert_eq!(
+            submissions.refusal(2023, 5, Part::One, &Answer::from(26000)),
+            None
+        );
+        assert_eq!(
+            submissions.refusal(2022, 4, Part::One, &Answer::from(26000)),
+            None
+        );
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("[2023.day_4.part1]"));
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,308 @@
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+
+use crate::table::Table;
+use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+use aoc_common::solution::{Part, RunReport};
+use log::error;
+
+/// Identifies an input without showing it, as puzzle inputs are not to be shared.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// How a computed answer compares to the one known to be right.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Unknown,
+    Match,
+    Mismatch { expected: Answer },
+}
+
+impl Check {
+    pub fn against(answer: &Answer, expected: Option<&Answer>) -> Self {
+        match expected {
+            None => Check::Unknown,
+            Some(expected) if expected == answer => Check::Match,
+            Some(expected) => Check::Mismatch {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved {
+        answer: Answer,
+        check: Check,
+    },
+    NotSolved,
+    /// The error, already logged when the row was added.
+    Failed(String),
+}
+
+#[derive(Debug)]
+pub struct Row {
+    pub day: u8,
+    pub input: String,
+    /// SHA-256 of the input text, when it could be read.
+    pub input_hash: Option<String>,
+    pub part: Part,
+    pub outcome: Outcome,
+    /// Parse time, only on the first part of each day.
+    pub parse: Option<Duration>,
+    pub elapsed: Duration,
+}
+
+impl Row {
+    /// A failure, or an answer other than the known one.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self.outcome,
+            Outcome::Failed(_)
+                | Outcome::Solved {
+                    check: Check::Mismatch { .. },
+                    ..
+                }
+        )
+    }
+
+    /// The known answer came out.
+    pub fn is_right(&self) -> bool {
+        matches!(
+            self.outcome,
+            Outcome::Solved {
+                check: Check::Match,
+                ..
+            }
+        )
+    }
+}
+
+/// Every part of a run over several days, to print as one table.
+#[derive(Debug, Default)]
+pub struct Summary {
+    pub rows: Vec<Row>,
+}
+
+impl Summary {
+    /// Record one day. Errors are logged as they come, the table only says "error".
+    pub fn add(
+        &mut self,
+        day: u8,
+        input: &str,
+        input_hash: Option<String>,
+        parts: &[Part],
+        report: Result<RunReport>,
+        expected: impl Fn(Part) -> Option<Answer>,
+    ) {
+        let report = match report {
+            Ok(report) => report,
+            Err(err) => {
+                error!("Day {}: {}", day, err);
+                for &part in parts {
+                    self.rows.push(Row {
+                        day,
+                        input: input.to_string(),
+                        input_hash: input_hash.clone(),
+                        part,
+                        outcome: Outcome::Failed(err.to_string()),
+                        parse: None,
+                        elapsed: Duration::ZERO,
+                    });
+                }
+                return;
+            }
+        };
+
+        for (i, part) in report.parts.into_iter().enumerate() {
+            let outcome = match part.answer {
+                Ok(answer) => Outcome::Solved {
+                    check: Check::against(&answer, expected(part.part).as_ref()),
+                    answer,
+                },
+                Err(Error::NotSolved(_)) => Outcome::NotSolved,
+                Err(err) => {
+                    error!("Day {} part {}: {}", day, part.part, err);
+                    Outcome::Failed(err.to_string())
+                }
+            };
+            self.rows.push(Row {
+                day,
+                input: input.to_string(),
+                input_hash: input_hash.clone(),
+                part: part.part,
+                outcome,
+                parse: (i == 0).then_some(report.parse),
+                elapsed: part.elapsed,
+            });
+        }
+    }
+
+    /// A failure or a wrong answer; unsolved parts are fine.
+    pub fn has_failures(&self) -> bool {
+        self.rows.iter().any(Row::is_wrong)
+    }
+
+    pub fn table(&self) -> Table {
+        let mut table = Table::new(&["Day", "Input", "Part", "Answer", "Parse", "Time", "Check"]);
+
+        for row in &self.rows {
+            let (answer, check) = match &row.outcome {
+                Outcome::Solved { answer, check } => {
+                    let check = match check {
+                        Check::Unknown => String::from("?"),
+                        Check::Match => String::from("ok"),
+                        Check::Mismatch { expected } => format!("WRONG, expected {}", expected),
+                    };
+                    (answer.to_string(), check)
+                }
+                Outcome::NotSolved => (String::from("not solved yet"), String::new()),
+                Outcome::Failed(_) => (String::from("error"), String::from("FAILED")),
+            };
+            table.row(vec![
+                row.day.to_string(),
+                row.input.clone(),
+                row.part.to_string(),
+                answer,
+                row.parse.map(|d| format!("{:.1?}", d)).unwrap_or_default(),
+                format!("{:.1?}", row.elapsed),
+                check,
+            ]);
+        }
+
+        let solved = self
+            .rows
+            .iter()
+            .filter(|row| matches!(row.outcome, Outcome::Solved { .. }))
+            .count();
+        let checked = self
+            .rows
+            .iter()
+            .filter(|row| {
+                matches!(
+                    row.outcome,
+                    Outcome::Solved {
+                        check: Check::Match | Check::Mismatch { .. },
+                        ..
+                    }
+                )
+            })
+            .count();
+        let matching = self
+            .rows
+            .iter()
+            .filter(|row| {
+                matches!(
+                    row.outcome,
+                    Outcome::Solved {
+                        check: Check::Match,
+                        ..
+                    }
+                )
+            })
+            .count();
+        let total_parse: Duration = self.rows.iter().filter_map(|row| row.parse).sum();
+        let total_time: Duration = self.rows.iter().map(|row| row.elapsed).sum();
+
+        table.footer(vec![
+            String::from("Total"),
+            String::new(),
+            self.rows.len().to_string(),
+            format!("{} solved", solved),
+            format!("{:.1?}", total_parse),
+            format!("{:.1?}", total_time),
+            format!("{}/{} ok", matching, checked),
+        ]);
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Check, Outcome, Summary};
+    use aoc_2023::day_3::Day3;
+    use aoc_2023::day_4::Day4;
+    use aoc_common::answer::Answer;
+    use aoc_common::solution::{Part, Runner};
+
+    #[test]
+    fn test_check_against() {
+        let answer = Answer::from(13);
+        assert_eq!(Check::against(&answer, None), Check::Unknown);
+        assert_eq!(
+            Check::against(&answer, Some(&Answer::from(13))),
+            Check::Match
+        );
+        assert_eq!(
+            Check::against(&answer, Some(&Answer::from(12))),
+            Check::Mismatch {
+                expected: Answer::from(12)
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut summary = Summary::default();
+        let example_3 = include_str!("../../aoc_2023/src/day_3/example.txt");
+        let example_4 = include_str!("../../aoc_2023/src/day_4/example.txt");
+
+        summary.add(
+            3,
+            "example",
+            None,
+            &Part::ALL,
+            Day3.run(example_3, &Part::ALL),
+            |_| None,
+        );
+        summary.add(
+            4,
+            "example",
+            None,
+            &Part::ALL,
+            Day4.run(example_4, &Part::ALL),
+            |part| Some(Answer::from(if part == Part::One { 13 } else { 30 })),
+        );
+
+        assert_eq!(summary.rows.len(), 4);
+        assert!(matches!(
+            summary.rows[0].outcome,
+            Outcome::Solved {
+                check: Check::Unknown,
+                ..
+            }
+        ));
+        assert_eq!(summary.rows[1].outcome, Outcome::NotSolved);
+        assert!(summary.rows[2].parse.is_some());
+        assert!(summary.rows[3].parse.is_none());
+        assert!(!summary.has_failures());
+
+        let table = summary.table().to_string();
+        let footer = table.lines().last().unwrap();
+        assert!(footer.starts_with("Total |         | 4"));
+        assert!(footer.contains("3 solved"));
+        assert!(footer.ends_with("2/2 ok"));
+    }
+
+    #[test]
+    fn test_parse_error_is_a_failure() {
+        let mut summary = Summary::default();
+        summary.add(
+            4,
+            "bad",
+            None,
+            &Part::ALL,
+            Day4.run("Card 1 | 2", &Part::ALL),
+            |_| None,
+        );
+        assert_eq!(summary.rows.len(), 2);
+        assert!(summary.has_failures());
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+/// A plain-text table whose columns are padded to their widest cell.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    footer: Option<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Self {
+        Table {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: vec![],
+            footer: None,
+        }
+    }
+
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    /// A last row, set apart from the others, typically for totals.
+    pub fn footer(&mut self, cells: Vec<String>) {
+        self.footer = Some(cells);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in self.rows.iter().chain(&self.footer) {
+            for (i, cell) in row.iter().enumerate() {
+                if i < widths.len() {
+                    widths[i] = widths[i].max(cell.chars().count());
+                }
+            }
+        }
+        widths
+    }
+}
+
+fn write_line(f: &mut fmt::Formatter<'_>, cells: &[String], widths: &[usize]) -> fmt::Result {
+    let line = widths
+        .iter()
+        .enumerate()
+        .map(|(i, width)| {
+            let cell = cells.get(i).map(String::as_str).unwrap_or("");
+            format!("{:<width$}", cell, width = width)
+        })
+        .collect::<Vec<String>>()
+        .join(" | ");
+    writeln!(f, "{}", line.trim_end())
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        write_line(f, &self.headers, &widths)?;
+
+        let separator = widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<String>>()
+            .join("-+-");
+        writeln!(f, "{}", separator)?;
+
+        for row in &self.rows {
+            write_line(f, row, &widths)?;
+        }
+
+        if let Some(footer) = &self.footer {
+            writeln!(f, "{}", separator)?;
+            write_line(f, footer, &widths)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Table;
+
+    #[test]
+    fn test_columns_are_aligned() {
+        let mut table = Table::new(&["Day", "Answer"]);
+        table.row(vec!["1".to_string(), "53515".to_string()]);
+        table.row(vec!["12".to_string(), "7".to_string()]);
+
+        assert_eq!(
+            table.to_string(),
+            "Day | Answer\n\
+             ----+-------\n\
+             1   | 53515\n\
+             12  | 7\n"
+        );
+    }
+
+    #[test]
+    fn test_footer_is_separated() {
+        let mut table = Table::new(&["Day", "Time"]);
+        table.row(vec!["1".to_string(), "2ms".to_string()]);
+        table.footer(vec!["Total".to_string(), "2ms".to_string()]);
+
+        assert_eq!(
+            table.to_string(),
+            "Day   | Time\n\
+             ------+-----\n\
+             1     | 2ms\n\
+             ------+-----\n\
+             Total | 2ms\n"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! `run --stream` through the built binary, reading a file and a pipe.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn example(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../aoc_2023/src")
+        .join(format!("day_{}/example.txt", day))
+}
+
+fn aoc(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // A run that stops before reading its input closes the pipe early.
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn test_stream_file() {
+    let input = example(4);
+    let output = aoc(
+        &["run", "-d", "4", "--stream", "-i", input.to_str().unwrap()],
+        "",
+    );
+    let stdout = stdout(&output);
+    assert!(stdout.contains("Day 4 part 1: 13 ("), "{}", stdout);
+    assert!(stdout.contains("Day 4 part 2: 30 ("), "{}", stdout);
+}
+
+#[test]
+fn test_stream_stdin() {
+    let game = std::fs::read_to_string(example(2)).unwrap();
+    let input = vec![game.trim_end(); 1000].join("\n");
+    let output = aoc(
+        &["run", "-d", "2", "-p", "2", "--stream", "-i", "-"],
+        &input,
+    );
+    assert!(stdout(&output).contains("Day 2 part 2: 2286000 ("));
+
+    let output = aoc(&["run", "-d", "2", "--stream", "-i", "-"], &input);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_stream_needs_a_streaming_day() {
+    let input = example(3);
+    let output = aoc(
+        &["run", "-d", "3", "--stream", "-i", input.to_str().unwrap()],
+        "",
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be streamed"));
+}
@@ -0,0 +1,97 @@
+//! The calibration of day 1 on large generated inputs, with the Aho-Corasick matcher,
+//! with the word replacements and regex it replaced (see `original.rs`), and with two
+//! regexes: one finding the first digit or word, one whose greedy `.*` finds the last.
+//!
+//! `cargo bench -p aoc_2023 --bench day_1`
+
+mod original;
+
+use aoc_2023::day_1::{Matcher, ENGLISH};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use regex::Regex;
+use std::hint::black_box;
+
+/// `lines` lines of digits, digit words and filler letters, the same on every run.
+fn generate(lines: usize) -> String {
+    let tokens: Vec<&str> = ENGLISH
+        .iter()
+        .map(|(word, _)| *word)
+        .chain(["1", "7", "twone", "eightwo", "x", "qz", "abc", "klmnp"])
+        .collect();
+    let mut seed: u64 = 0x2023_1201;
+    let mut next = move |n: usize| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize % n
+    };
+
+    let mut input = String::new();
+    for _ in 0..lines {
+        for _ in 0..4 + next(12) {
+            input.push_str(tokens[next(tokens.len())]);
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn with_regex(input: &str) -> i32 {
+    let words = ENGLISH
+        .iter()
+        .map(|(word, _)| *word)
+        .collect::<Vec<_>>()
+        .join("|");
+    let first = Regex::new(&format!(r"\d|{}", words)).unwrap();
+    let last = Regex::new(&format!(r".*(\d|{})", words)).unwrap();
+    let value = |token: &str| match token.parse::<i32>() {
+        Ok(digit) => digit,
+        Err(_) => ENGLISH
+            .iter()
+            .find(|(word, _)| *word == token)
+            .map_or(0, |&(_, digit)| digit as i32),
+    };
+
+    input
+        .lines()
+        .map(
+            |line| match (first.find(line), last.captures(line).and_then(|c| c.get(1))) {
+                (Some(a), Some(b)) => value(a.as_str()) * 10 + value(b.as_str()),
+                _ => 0,
+            },
+        )
+        .sum()
+}
+
+fn with_matcher(input: &str) -> i32 {
+    let matcher = Matcher::new(ENGLISH);
+    input.lines().map(|line| matcher.calibration(line)).sum()
+}
+
+fn calibration(c: &mut Criterion) {
+    let example = include_str!("../../src/day_1/example_2.txt");
+    assert_eq!(original::calibration_sum(example.lines()), 281);
+
+    let mut group = c.benchmark_group("day_1");
+    for lines in [1_000, 100_000] {
+        let input = generate(lines);
+        assert_eq!(with_matcher(&input), with_regex(&input));
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("aho-corasick", lines),
+            &input,
+            |b, input| b.iter(|| with_matcher(black_box(input))),
+        );
+        group.bench_with_input(BenchmarkId::new("regex", lines), &input, |b, input| {
+            b.iter(|| with_regex(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("original", lines), &input, |b, input| {
+            b.iter(|| original::calibration_sum(black_box(input).lines()))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, calibration);
+criterion_main!(benches);
@@ -0,0 +1,124 @@
+//! Day 1 part 2 as the crate first solved it, before the Aho-Corasick matcher: the
+//! known overlapping pairs, then each digit word, are replaced in the line with
+//! `find`/`rfind`, and a regex picks the first and last digits of what is left.
+//! Kept as it was, logging aside, to measure the matcher against.
+//!
+//! Some overlaps it does not know, such as "twone", come out wrong: its sum is only
+//! timed, not checked.
+
+use regex::Regex;
+
+const ARRAY_DOUBLES: [(&str, &str); 4] = [
+    ("oneight", "18"),
+    ("eightwo", "82"),
+    ("eighthree", "83"),
+    ("sevenine", "79"),
+];
+
+const ARRAY_FROM_LETTERS_TO_INT: [(&str, &str); 9] = [
+    ("one", "1"),
+    ("two", "2"),
+    ("three", "3"),
+    ("four", "4"),
+    ("five", "5"),
+    ("six", "6"),
+    ("seven", "7"),
+    ("eight", "8"),
+    ("nine", "9"),
+];
+
+pub fn calibration_sum<'a>(lines: impl Iterator<Item = &'a str>) -> i32 {
+    // https://docs.rs/regex/1.10.2/regex/struct.Captures.html
+    let re: Regex = Regex::new(r"^[^\d]*(\d){0,1}.*(\d)[^\d]*$").unwrap();
+
+    let mut sum = 0;
+    for line in lines {
+        let mut new_line = line.to_string();
+        new_line = replace_letters_to_digits(new_line, ARRAY_DOUBLES.to_vec());
+        new_line = replace_letters_to_digits(new_line, ARRAY_FROM_LETTERS_TO_INT.to_vec());
+
+        sum += count_int_line(&re, &new_line);
+    }
+    sum
+}
+
+fn count_int_line(re: &Regex, new_line: &str) -> i32 {
+    if let Some(table) = re.captures(new_line) {
+        let table_values = table
+            .iter()
+            .filter(|c| c.is_some())
+            .skip(1)
+            .map(|c| c.unwrap().as_str())
+            .collect::<Vec<&str>>();
+
+        if table_values.is_empty() || table_values.len() > 2 {
+            return 0;
+        }
+
+        return [
+            *table_values.first().unwrap(),
+            *table_values.last().unwrap(),
+        ]
+        .join("")
+        .parse::<i32>()
+        .unwrap_or_else(|_| panic!("Could not parse {:?}", table_values));
+    }
+    0
+}
+
+fn replace_letters_to_digits(line: String, array_of_tuples: Vec<(&str, &str)>) -> String {
+    let mut new_line = line;
+    let mut indexes = vec![];
+
+    for (index_in_array, (digit, _)) in array_of_tuples.clone().iter().enumerate() {
+        if let Some(index) = new_line.find(digit) {
+            indexes.push((index, index_in_array));
+
+            if let Some(index_second) = new_line.rfind(digit) {
+                if index_second != index {
+                    indexes.push((index_second, index_in_array));
+                }
+            }
+        }
+    }
+
+    if !indexes.is_empty() {
+        indexes.sort_by_key(|&k| k.0);
+
+        if indexes.len() == 1 {
+            replace_in_line(&mut new_line, indexes.first(), array_of_tuples.clone());
+        } else if indexes.len() > 1 {
+            if !start_with_digits(&new_line.chars().rev().collect::<String>()) {
+                replace_in_line(&mut new_line, indexes.last(), array_of_tuples.clone());
+            }
+
+            if !start_with_digits(&new_line) {
+                let (index_in_str, index_in_array) = indexes.first().unwrap();
+
+                let tuple: (&str, &str) = array_of_tuples[*index_in_array];
+                if new_line.find(tuple.0).is_some_and(|x| x == *index_in_str) {
+                    new_line.replace_range(index_in_str..&(tuple.0.len() + index_in_str), tuple.1);
+                }
+            }
+        }
+    }
+    new_line
+}
+
+fn replace_in_line(
+    new_line: &mut String,
+    indexes: Option<&(usize, usize)>,
+    array_of_tuples: Vec<(&str, &str)>,
+) {
+    let (index_in_str, index_in_array) = indexes.unwrap();
+
+    let tuple: (&str, &str) = array_of_tuples[*index_in_array];
+    new_line.replace_range(index_in_str..&(tuple.0.len() + index_in_str), tuple.1);
+}
+
+fn start_with_digits(new_line: &str) -> bool {
+    if let Some(c) = new_line.get(..1) {
+        return c.parse::<i32>().is_ok();
+    }
+    false
+}
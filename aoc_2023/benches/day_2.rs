@@ -0,0 +1,84 @@
+//! Parsing and solving day 2 on large generated inputs, against the regexes it first
+//! used: one for the game number, and one per color compiled on every line.
+//!
+//! `cargo bench -p aoc_2023 --bench day_2`
+
+use aoc_2023::day_2::Day2;
+use aoc_common::answer::Answer;
+use aoc_common::solution::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use regex::Regex;
+use std::hint::black_box;
+
+/// `games` games of one to six draws, the same on every run.
+fn generate(games: usize) -> String {
+    let mut seed: u64 = 0x2023_1202;
+    let mut next = move |n: u64| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) % n
+    };
+
+    let mut input = String::new();
+    for game in 1..=games {
+        let draws: Vec<String> = (0..1 + next(6))
+            .map(|_| {
+                format!(
+                    "{} blue, {} red, {} green",
+                    1 + next(20),
+                    1 + next(20),
+                    1 + next(20)
+                )
+            })
+            .collect();
+        input.push_str(&format!("Game {}: {}\n", game, draws.join("; ")));
+    }
+    input
+}
+
+fn with_day_2(input: &str) -> Answer {
+    let day = Day2::new(12, 13, 14);
+    day.part2(&day.parse(input).unwrap()).unwrap()
+}
+
+/// The sum of powers as the first version found it.
+fn with_regexes(input: &str) -> i32 {
+    let re_game_number = Regex::new(r"Game (\d*):.*").unwrap();
+    let count = |line: &str, color: &str| {
+        let re_color = Regex::new(&format!("(\\d*) {}", color)).unwrap();
+        re_color
+            .captures_iter(line)
+            .map(|c| c[1].parse::<i32>().unwrap())
+            .max()
+            .unwrap_or(0)
+    };
+
+    input
+        .lines()
+        .filter(|line| re_game_number.is_match(line))
+        .map(|line| count(line, "blue") * count(line, "red") * count(line, "green"))
+        .sum()
+}
+
+fn games(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_2");
+    // The regexes are compiled on every line, a few samples are plenty.
+    group.sample_size(10);
+    for games in [1_000, 10_000] {
+        let input = generate(games);
+        assert_eq!(with_day_2(&input), Answer::from(with_regexes(&input)));
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("day_2", games), &input, |b, input| {
+            b.iter(|| with_day_2(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("regexes", games), &input, |b, input| {
+            b.iter(|| with_regexes(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, games);
+criterion_main!(benches);
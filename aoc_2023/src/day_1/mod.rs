@@ -17,208 +17,322 @@ In this example, the calibration values of these four lines are 12, 38, 15, and
 Adding these together produces 142.
  */
 
-use regex::Regex;
-use std::fs;
-use std::vec::IntoIter;
-
-const ARRAY_DOUBLES: [(&str, &str); 4] = [
-    ("oneight", "18"),
-    ("eightwo", "82"),
-    ("eighthree", "83"),
-    ("sevenine", "79"),
+use aho_corasick::automaton::Automaton;
+use aho_corasick::dfa::DFA;
+use aho_corasick::{AhoCorasick, Anchored, MatchKind};
+use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+use aoc_common::input::Source;
+use aoc_common::parse;
+use aoc_common::solution::{Part, Runner, Solution, Streaming};
+use log::{debug, trace};
+use std::io::BufRead;
+
+/// Tokens spelling out digits, each with the digit it stands for.
+pub type Vocabulary<'a> = [(&'a str, u32)];
+
+/// The words of the puzzle.
+pub const ENGLISH: &Vocabulary = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
 ];
 
-const ARRAY_FROM_LETTERS_TO_INT: [(&str, &str); 9] = [
-    ("one", "1"),
-    ("two", "2"),
-    ("three", "3"),
-    ("four", "4"),
-    ("five", "5"),
-    ("six", "6"),
-    ("seven", "7"),
-    ("eight", "8"),
-    ("nine", "9"),
+/// The words of the puzzle, in French.
+pub const FRENCH: &Vocabulary = &[
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
 ];
 
-fn read_file(file_path: &str) -> IntoIter<String> {
-    let content = fs::read_to_string(file_path).expect("Could not read");
-    content
-        .split("\n")
-        .map(|s| String::from(s))
-        .collect::<Vec<String>>()
-        .into_iter()
+/// The words of the puzzle, in German.
+pub const GERMAN: &Vocabulary = &[
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+/// A built-in vocabulary by language name or code, such as `french` or `fr`.
+pub fn builtin(name: &str) -> Option<&'static Vocabulary<'static>> {
+    match name.to_lowercase().as_str() {
+        "english" | "en" => Some(ENGLISH),
+        "french" | "fr" => Some(FRENCH),
+        "german" | "de" => Some(GERMAN),
+        _ => None,
+    }
+}
+
+/// A vocabulary written one `token = digit` per line; blank lines and lines
+/// starting with `#` are skipped. The tokens borrow from `text`.
+///
+/// A token may appear once, and may not be a digit standing for another one:
+/// the first and the last digit of a line would not agree on its value.
+pub fn parse_vocabulary(text: &str) -> Result<Vec<(&str, u32)>> {
+    let mut tokens: Vec<(&str, u32)> = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((token, digit)) = line.split_once('=') else {
+            return Err(Error::parse(i, line, "expected `token = digit`"));
+        };
+        let token = token.trim();
+        if token.is_empty() {
+            return Err(Error::parse(i, line, "empty token"));
+        }
+        let digit = match digit.trim().parse::<u32>() {
+            Ok(digit) if digit <= 9 => digit,
+            _ => return Err(Error::parse(i, line, "the value must be a digit, 0 to 9")),
+        };
+        if let Some((_, first)) = tokens.iter().find(|(known, _)| *known == token) {
+            let message = format!("'{}' is already {}", token, first);
+            return Err(Error::parse(i, line, message));
+        }
+        if token.parse::<u32>().is_ok_and(|value| value != digit) {
+            let message = format!("'{}' is a digit of its own", token);
+            return Err(Error::parse(i, line, message));
+        }
+        tokens.push((token, digit));
+    }
+    Ok(tokens)
 }
 
-pub fn run_day_1(file_name: &str) -> i32 {
-    // https://docs.rs/regex/1.10.2/regex/struct.Captures.html
+/// Part 2 counts the tokens of a vocabulary as digits, English ones in the puzzle.
+pub struct Day1<'a> {
+    vocabulary: Tokens<'a>,
+}
 
-    let lines = read_file(format!("src/day_1/{}", file_name).as_str());
-    //let lines = read_file("src/day_1/exemple_bonus.txt");
-    //let lines = read_file("src/day_1/exemple.txt");
-    let re: Regex = Regex::new(r"^[^\d]*(\d){0,1}.*(\d)[^\d]*$").unwrap();
+/// A built-in vocabulary, or one read with the day's option.
+enum Tokens<'a> {
+    Borrowed(&'a Vocabulary<'a>),
+    Owned(Vec<(String, u32)>),
+}
 
-    let mut sum = 0;
-    for (i, line) in lines.enumerate() {
-        print!("{}, '{}' / ", i, String::from(line.clone()));
-        let mut new_line = String::from(line);
-        new_line = replace_letters_to_digits(new_line, ARRAY_DOUBLES.to_vec());
-        new_line = replace_letters_to_digits(new_line, ARRAY_FROM_LETTERS_TO_INT.to_vec());
+impl<'a> Day1<'a> {
+    pub const ENGLISH: Day1<'static> = Day1::new(ENGLISH);
 
-        let i1 = count_int_line(&re, &new_line);
-        sum += i1;
-        println!("'{}' => {} (sum = {})", new_line, i1, sum)
+    pub const fn new(vocabulary: &'a Vocabulary<'a>) -> Self {
+        Day1 {
+            vocabulary: Tokens::Borrowed(vocabulary),
+        }
     }
 
-    println!("{}", sum);
-    return sum;
+    /// Part 1 only knows the digits, part 2 also the tokens of the vocabulary.
+    fn matcher(&self, part: Part) -> Matcher {
+        match (part, &self.vocabulary) {
+            (Part::One, _) => Matcher::new::<&str>(&[]),
+            (Part::Two, Tokens::Borrowed(vocabulary)) => Matcher::new(vocabulary),
+            (Part::Two, Tokens::Owned(vocabulary)) => Matcher::new(vocabulary),
+        }
+    }
 }
 
-fn count_int_line(re: &Regex, new_line: &String) -> i32 {
-    if let Some(table) = re.captures(&new_line) {
-        let table_values = table
-            .iter()
-            .filter(|c| c.is_some())
-            .skip(1)
-            .map(|c| c.unwrap().as_str())
-            .collect::<Vec<&str>>();
+impl Solution for Day1<'_> {
+    type Input = Vec<String>;
 
-        if table_values.is_empty() || table_values.len() > 2 {
-            return 0;
-        }
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        Ok(input.split('\n').map(String::from).collect())
+    }
 
-        return [
-            *table_values.first().unwrap(),
-            *table_values.last().unwrap(),
-        ]
-        .join("")
-        .parse::<i32>()
-        .expect(format!("Could not parse {:?}", table_values).as_str());
+    /// Only the digits written as such.
+    fn part1(&self, lines: &Vec<String>) -> Result<Answer> {
+        Ok(calibration_sum(lines, &self.matcher(Part::One)).into())
     }
-    0
-}
 
-fn replace_letters_to_digits(line: String, array_of_tuples: Vec<(&str, &str)>) -> String {
-    let mut new_line = String::from(line);
-    let mut indexes = vec![];
+    /// Digits, and digits spelled out with the tokens of the vocabulary.
+    fn part2(&self, lines: &Vec<String>) -> Result<Answer> {
+        Ok(calibration_sum(lines, &self.matcher(Part::Two)).into())
+    }
 
-    for (index_in_array, (digit, _)) in array_of_tuples.clone().iter().enumerate() {
-        if let Some(index) = new_line.find(digit) {
-            indexes.push((index, index_in_array));
+    fn streaming(&self) -> Option<&dyn Streaming> {
+        Some(self)
+    }
 
-            if let Some(index_second) = new_line.rfind(digit) {
-                if index_second != index {
-                    indexes.push((index_second, index_in_array));
-                }
-            }
+    /// `option` names a built-in vocabulary, or a file of `token = digit` lines.
+    fn with_option(&self, option: &str) -> Result<Box<dyn Runner>> {
+        if let Some(vocabulary) = builtin(option) {
+            return Ok(Box::new(Day1::new(vocabulary)));
         }
+        let source = Source::File(option.into());
+        let text = source.read()?;
+        let tokens = parse_vocabulary(&text).map_err(|e| e.in_file(source.describe()))?;
+        let tokens = tokens
+            .into_iter()
+            .map(|(token, digit)| (token.to_string(), digit))
+            .collect();
+        Ok(Box::new(Day1 {
+            vocabulary: Tokens::Owned(tokens),
+        }))
     }
+}
 
-    if !indexes.is_empty() {
-        indexes.sort_by_key(|&k| k.0);
+impl Streaming for Day1<'_> {
+    fn solve_reader(&self, reader: &mut dyn BufRead, part: Part) -> Result<Answer> {
+        let matcher = self.matcher(part);
+        let mut sum = 0;
+        parse::each_line(reader, |line| {
+            sum += i64::from(matcher.calibration(line));
+            Ok(())
+        })?;
+        debug!("calibration sum: {}", sum);
+        Ok(sum.into())
+    }
+}
 
-        if indexes.len() == 1 {
-            replace_in_line(&mut new_line, indexes.first(), array_of_tuples.clone());
-        } else if indexes.len() > 1 {
-            if !start_with_digits(&new_line.chars().rev().collect::<String>()) {
-                replace_in_line(&mut new_line, indexes.last(), array_of_tuples.clone());
-            }
+fn calibration_sum(lines: &[String], matcher: &Matcher) -> i32 {
+    let mut sum = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let i1 = matcher.calibration(line);
+        sum += i1;
+        trace!("{}, '{}' => {} (sum = {})", i, line, i1, sum);
+    }
 
-            if !start_with_digits(&new_line) {
-                let (index_in_str, index_in_array) = indexes.first().unwrap();
+    debug!("calibration sum: {}", sum);
+    sum
+}
 
-                let tuple: (&str, &str) = array_of_tuples[*index_in_array];
-                if new_line.find(tuple.0).is_some_and(|x| x == *index_in_str) {
-                    new_line.replace_range(index_in_str..&(tuple.0.len() + index_in_str), tuple.1);
-                }
-            }
+/// The ten digits and the tokens of a vocabulary in one Aho-Corasick automaton: the
+/// first digit of a line is found in one pass from its start, the last one in one pass
+/// from its end. Tokens may share letters, as in "twone".
+pub struct Matcher {
+    /// Leftmost-longest, so the first match is the one starting first.
+    forward: AhoCorasick,
+    /// The tokens reversed, fed the line from its last byte.
+    backward: DFA,
+    /// The digit of each pattern, by pattern id.
+    digits: Vec<u32>,
+}
+
+impl Matcher {
+    pub fn new<S: AsRef<str>>(vocabulary: &[(S, u32)]) -> Self {
+        let digits: Vec<String> = (0..=9).map(|d: u32| d.to_string()).collect();
+        let patterns: Vec<(&str, u32)> = digits
+            .iter()
+            .zip(0..)
+            .map(|(d, value)| (d.as_str(), value))
+            .chain(
+                vocabulary
+                    .iter()
+                    .map(|(token, digit)| (token.as_ref(), *digit)),
+            )
+            .filter(|(token, _)| !token.is_empty())
+            .collect();
+
+        let forward = AhoCorasick::builder()
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(patterns.iter().map(|(token, _)| token))
+            .expect("a few short tokens always fit in an automaton");
+        let backward = DFA::new(
+            patterns
+                .iter()
+                .map(|(token, _)| token.bytes().rev().collect::<Vec<u8>>()),
+        )
+        .expect("a few short tokens always fit in an automaton");
+
+        Matcher {
+            forward,
+            backward,
+            digits: patterns.iter().map(|&(_, digit)| digit).collect(),
         }
     }
-    new_line
-}
 
-fn replace_in_line(
-    new_line: &mut String,
-    indexes: Option<&(usize, usize)>,
-    array_of_tuples: Vec<(&str, &str)>,
-) {
-    let (index_in_str, index_in_array) = indexes.unwrap();
+    /// The digit starting first in `line`, the longest token where several start.
+    pub fn first(&self, line: &str) -> Option<u32> {
+        self.forward
+            .find(line)
+            .map(|m| self.digits[m.pattern().as_usize()])
+    }
 
-    let tuple: (&str, &str) = array_of_tuples[*index_in_array];
-    new_line.replace_range(index_in_str..&(tuple.0.len() + index_in_str), tuple.1);
-}
+    /// The digit starting last in `line`, the longest token where several start.
+    pub fn last(&self, line: &str) -> Option<u32> {
+        let automaton = &self.backward;
+        let mut state = automaton
+            .start_state(Anchored::No)
+            .expect("the automaton is built for unanchored searches");
+        for &byte in line.as_bytes().iter().rev() {
+            state = automaton.next_state(Anchored::No, state, byte);
+            if automaton.is_match(state) {
+                // The tokens matching here all start at this byte of the line.
+                let longest = (0..automaton.match_len(state))
+                    .map(|i| automaton.match_pattern(state, i))
+                    .max_by_key(|&pattern| automaton.pattern_len(pattern))?;
+                return Some(self.digits[longest.as_usize()]);
+            }
+        }
+        None
+    }
 
-fn start_with_digits(new_line: &String) -> bool {
-    if let Some(c) = new_line.get(..1) {
-        return c.parse::<i32>().is_ok();
+    /// The first and the last digit of `line` as a two-digit number, 0 when it has none.
+    pub fn calibration(&self, line: &str) -> i32 {
+        match (self.first(line), self.last(line)) {
+            (Some(first), Some(last)) => (first * 10 + last) as i32,
+            _ => 0,
+        }
     }
-    false
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{
-        count_int_line, replace_letters_to_digits, run_day_1, start_with_digits, ARRAY_DOUBLES,
-        ARRAY_FROM_LETTERS_TO_INT,
-    };
-    use regex::Regex;
+    use super::{builtin, parse_vocabulary, Day1, Matcher, Vocabulary, ENGLISH, FRENCH, GERMAN};
+    use aoc_common::answer::Answer;
+    use aoc_common::error::Error;
+    use aoc_common::solution::{Part, Solution, Streaming};
+    use proptest::prelude::*;
+
+    fn count_int_line(line: &str, vocabulary: &Vocabulary) -> i32 {
+        Matcher::new(vocabulary).calibration(line)
+    }
 
     #[test]
-    fn test_day_one() {
-        assert_eq!(run_day_1("exemple.txt"), 142);
-        assert_eq!(run_day_1("exemple_bonus.txt"), 281);
+    fn test_part1() {
+        let example = Day1::ENGLISH.parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Day1::ENGLISH.part1(&example).unwrap(), Answer::from(142));
+        assert_eq!(count_int_line("two1nine", &[]), 11);
+        assert_eq!(count_int_line("abcdef", &[]), 0);
     }
 
     #[test]
-    fn test_start_with_digits() {
-        assert!(start_with_digits(&"123".to_string()));
-        assert!(start_with_digits(&"1".to_string()));
-        assert!(!start_with_digits(&"ab3".to_string()))
+    fn test_part2() {
+        let example = Day1::ENGLISH.parse(include_str!("example_2.txt")).unwrap();
+        assert_eq!(Day1::ENGLISH.part2(&example).unwrap(), Answer::from(281));
+        assert_eq!(count_int_line("two1nine", ENGLISH), 29);
     }
 
     #[test]
-    fn replace_letters_to_digits_test() {
-        let array = ARRAY_FROM_LETTERS_TO_INT.to_vec();
-        assert_eq!(
-            replace_letters_to_digits("five712".to_string(), array.clone()),
-            "5712"
-        );
+    fn test_streaming() {
+        let mut example = include_str!("example.txt").as_bytes();
         assert_eq!(
-            replace_letters_to_digits(
-                "tzheightzlzmsqlnxfqzrlbhbdpbnbdjns6".to_string(),
-                array.clone()
-            ),
-            "tzh8zlzmsqlnxfqzrlbhbdpbnbdjns6"
+            Day1::ENGLISH.solve_reader(&mut example, Part::One).unwrap(),
+            Answer::from(142)
         );
+        let mut example = include_str!("example_2.txt").as_bytes();
         assert_eq!(
-            replace_letters_to_digits("57eight9fivefiveeight".to_string(), array.clone()),
-            "57eight9fivefive8"
-        );
-        assert_eq!(
-            replace_letters_to_digits("52fourfour7sxzptgtnhsfour82".to_string(), array.clone()),
-            "52fourfour7sxzptgtnhsfour82"
-        );
-        assert_eq!(
-            replace_letters_to_digits("nine8bgh921seven".to_string(), array.clone()),
-            "98bgh9217"
-        );
-        assert_eq!(
-            replace_letters_to_digits("five6fivethree2three".to_string(), array.clone()),
-            "56fivethree23"
-        );
-        assert_eq!(
-            replace_letters_to_digits("seven22".to_string(), array.clone()),
-            "722"
-        );
-        assert_eq!(
-            replace_letters_to_digits("7pqrstsixteen".to_string(), array.clone()),
-            "7pqrst6teen"
+            Day1::ENGLISH.solve_reader(&mut example, Part::Two).unwrap(),
+            Answer::from(281)
         );
     }
 
     #[test]
     fn count_in_line_test() {
-        let re: Regex = Regex::new(r"^[^\d]*(\d){0,1}.*(\d)[^\d]*$").unwrap();
-
         let to_test = vec![
             ("8blsrrqrjlckv7xszllqddzn5oneightfg", 88),
             ("five712", 52),
@@ -249,25 +363,208 @@ mod tests {
             ("1seventwoseven", 17),
         ];
 
-        let array = ARRAY_FROM_LETTERS_TO_INT.to_vec();
         for (from, to) in to_test {
-            assert_eq!(
-                count_int_line(
-                    &re,
-                    &replace_letters_to_digits(from.to_string(), array.clone())
-                ),
-                to
-            );
+            assert_eq!(count_int_line(from, ENGLISH), to, "{}", from);
+        }
+    }
+
+    /// Only letters that no digit word contains, so they cannot create or join words.
+    const FILLER: &str = "[abcdjklmpqyz]{1,3}";
+
+    /// Words sharing letters with their neighbour, where both digits count.
+    const OVERLAPS: [(&str, i32, i32); 9] = [
+        ("oneight", 1, 8),
+        ("twone", 2, 1),
+        ("threeight", 3, 8),
+        ("fiveight", 5, 8),
+        ("sevenine", 7, 9),
+        ("eightwo", 8, 2),
+        ("eighthree", 8, 3),
+        ("nineight", 9, 8),
+        ("twoneight", 2, 8),
+    ];
+
+    /// The calibration value spelled out: try a digit or a token at every character.
+    fn reference(line: &str, vocabulary: &Vocabulary) -> i32 {
+        let digits: Vec<i32> = line
+            .char_indices()
+            .filter_map(|(i, c)| {
+                let rest = &line[i..];
+                c.to_digit(10).map(|d| d as i32).or_else(|| {
+                    let mut found: Vec<&(&str, u32)> = vocabulary
+                        .iter()
+                        .filter(|(token, _)| rest.starts_with(token))
+                        .collect();
+                    found.sort_by_key(|(token, _)| token.len());
+                    found.last().map(|(_, digit)| *digit as i32)
+                })
+            })
+            .collect();
+        match (digits.first(), digits.last()) {
+            (Some(first), Some(last)) => first * 10 + last,
+            _ => 0,
+        }
+    }
+
+    fn token(vocabulary: &'static Vocabulary<'static>) -> impl Strategy<Value = String> {
+        prop::sample::select(
+            vocabulary
+                .iter()
+                .map(|(token, _)| token.to_string())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn word() -> impl Strategy<Value = String> {
+        token(ENGLISH)
+    }
+
+    fn overlap() -> impl Strategy<Value = String> {
+        prop::sample::select(OVERLAPS.map(|(words, _, _)| words.to_string()).to_vec())
+    }
+
+    /// Lines of digits, words and filler, where no two words share a letter.
+    fn separate_words() -> impl Strategy<Value = String> {
+        prop::collection::vec(prop_oneof!["[0-9]", word(), FILLER], 0..10)
+            .prop_map(|tokens| tokens.concat())
+    }
+
+    /// Lines of tokens glued to each other or to any letter, so that they overlap.
+    fn glued_tokens(vocabulary: &'static Vocabulary<'static>) -> impl Strategy<Value = String> {
+        prop::collection::vec(
+            prop_oneof!["[0-9]", token(vocabulary), "[a-zü]{1,2}"],
+            0..10,
+        )
+        .prop_map(|tokens| tokens.concat())
+    }
+
+    /// Lines also made of overlapping words, and of any letter.
+    fn overlapping_words() -> impl Strategy<Value = String> {
+        prop::collection::vec(prop_oneof!["[0-9]", word(), overlap(), "[a-z]{1,3}"], 0..10)
+            .prop_map(|tokens| tokens.concat())
+    }
+
+    #[test]
+    fn test_reference() {
+        assert_eq!(reference("two1nine", ENGLISH), 29);
+        assert_eq!(reference("abcone2threexyz", ENGLISH), 13);
+        assert_eq!(reference("7pqrstsixteen", ENGLISH), 76);
+        assert_eq!(reference("m4", ENGLISH), 44);
+        assert_eq!(reference("xyz", ENGLISH), 0);
+        for (line, first, last) in OVERLAPS {
+            assert_eq!(reference(line, ENGLISH), first * 10 + last, "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_overlaps() {
+        for (line, first, last) in OVERLAPS {
+            assert_eq!(count_int_line(line, ENGLISH), first * 10 + last, "{}", line);
+        }
+        assert_eq!(count_int_line("xtwonex", ENGLISH), 21);
+        assert_eq!(count_int_line("", ENGLISH), 0);
+    }
+
+    #[test]
+    fn test_matcher_both_ways() {
+        let matcher = Matcher::new(ENGLISH);
+        assert_eq!(matcher.first("xtwonex"), Some(2));
+        assert_eq!(matcher.last("xtwonex"), Some(1));
+        assert_eq!(matcher.first("abc7"), Some(7));
+        assert_eq!(matcher.last("0abc"), Some(0));
+        assert_eq!(matcher.first("none"), Some(1));
+        assert_eq!(matcher.last("xyz"), None);
+
+        // Where tokens start at the same byte, the longest wins both ways; where one
+        // holds another, the one starting first or last does.
+        let vocabulary = [("a", 1), ("ab", 2), ("abcd", 3), ("bc", 4)];
+        let matcher = Matcher::new(&vocabulary);
+        assert_eq!(matcher.first("xabx"), Some(2));
+        assert_eq!(matcher.last("xabx"), Some(2));
+        assert_eq!(matcher.first("abcd"), Some(3));
+        assert_eq!(matcher.last("abcd"), Some(4));
+    }
+
+    #[test]
+    fn test_french() {
+        let day = Day1::new(FRENCH);
+        let input = day.parse("deux1neuf\ncinquatre\ntroisept\n7huit").unwrap();
+        assert_eq!(day.part2(&input).unwrap(), Answer::from(29 + 54 + 37 + 78));
+        assert_eq!(day.part1(&input).unwrap(), Answer::from(11 + 77));
+    }
+
+    #[test]
+    fn test_german() {
+        let day = Day1::new(GERMAN);
+        let input = day.parse("zweins\nfünfacht\ndreins9\nxsiebenx").unwrap();
+        assert_eq!(day.part2(&input).unwrap(), Answer::from(21 + 58 + 39 + 77));
+    }
+
+    #[test]
+    fn test_custom_vocabulary() {
+        let text = "# roman numerals\ni = 1\nii = 2\n\niv = 4\nv=5\n";
+        let vocabulary = parse_vocabulary(text).unwrap();
+        assert_eq!(vocabulary, vec![("i", 1), ("ii", 2), ("iv", 4), ("v", 5)]);
+
+        // The longest token wins where several start.
+        assert_eq!(count_int_line("xivx", &vocabulary), 45);
+        assert_eq!(count_int_line("ii", &vocabulary), 21);
+        assert_eq!(count_int_line("3ii", &vocabulary), 31);
+        let day = Day1::new(&vocabulary);
+        let input = day.parse("iv3\nxxvii").unwrap();
+        assert_eq!(day.part2(&input).unwrap(), Answer::from(43 + 51));
+    }
+
+    #[test]
+    fn test_parse_vocabulary_errors() {
+        assert!(parse_vocabulary("one 1").is_err());
+        assert!(parse_vocabulary(" = 1").is_err());
+        assert!(parse_vocabulary("ten = 10").is_err());
+        assert!(parse_vocabulary("one = x").is_err());
+        assert_eq!(parse_vocabulary("\n# nothing\n").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_parse_vocabulary_duplicates() {
+        let err = parse_vocabulary("i = 1\nv = 5\ni = 2").unwrap_err();
+        assert!(
+            matches!(&err, Error::Parse(e) if e.line == 3 && e.text == "i = 2"),
+            "{}",
+            err
+        );
+        assert!(parse_vocabulary("i = 1\ni = 1").is_err());
+        let err = parse_vocabulary("x = 1\n7 = 1").unwrap_err();
+        assert!(matches!(&err, Error::Parse(e) if e.line == 2), "{}", err);
+        assert_eq!(parse_vocabulary("7 = 7").unwrap(), vec![("7", 7)]);
+    }
+
+    #[test]
+    fn test_builtin() {
+        assert_eq!(builtin("fr"), Some(FRENCH));
+        assert_eq!(builtin("German"), Some(GERMAN));
+        assert_eq!(builtin("english"), Some(ENGLISH));
+        assert_eq!(builtin("klingon"), None);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_reference_on_separate_words(line in separate_words()) {
+            prop_assert_eq!(count_int_line(&line, ENGLISH), reference(&line, ENGLISH), "{}", line);
+        }
+
+        #[test]
+        fn prop_matches_reference_on_overlapping_words(line in overlapping_words()) {
+            prop_assert_eq!(count_int_line(&line, ENGLISH), reference(&line, ENGLISH), "{}", line);
+        }
+
+        #[test]
+        fn prop_matches_reference_in_french(line in glued_tokens(FRENCH)) {
+            prop_assert_eq!(count_int_line(&line, FRENCH), reference(&line, FRENCH), "{}", line);
         }
 
-        for (from, to) in [("eighthree", 83), ("sevenine", 79)] {
-            assert_eq!(
-                count_int_line(
-                    &re,
-                    &replace_letters_to_digits(from.to_string(), ARRAY_DOUBLES.clone().to_vec())
-                ),
-                to
-            );
+        #[test]
+        fn prop_matches_reference_in_german(line in glued_tokens(GERMAN)) {
+            prop_assert_eq!(count_int_line(&line, GERMAN), reference(&line, GERMAN), "{}", line);
         }
     }
 }
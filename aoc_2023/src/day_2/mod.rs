@@ -1,126 +1,204 @@
-use regex::Regex;
-use std::fs;
-use std::num::ParseIntError;
+use aoc_common::answer::Answer;
+use aoc_common::error::Result;
+use aoc_common::parse;
+use aoc_common::solution::{Part, Solution, Streaming};
+use log::{debug, trace};
+use std::io::BufRead;
 
 /// From "Game 85: 1 red, 2 blue, 9 green; ..." return 85
-fn extract_game_number(re: Regex, line: &str) -> Result<i32, ParseIntError> {
-    if let Some(ma) = re.captures(line).and_then(|ma| ma.get(1)) {
-        return ma.as_str().parse::<i32>();
-    } else {
-        panic!("Could not find 'Game Number'")
-    }
+fn extract_game_number(line: &str) -> std::result::Result<i32, String> {
+    let Some((_, after)) = line.split_once("Game ") else {
+        return Err(String::from("could not find 'Game Number'"));
+    };
+    let number = after.split_once(':').map_or(after, |(number, _)| number);
+    number
+        .parse::<i32>()
+        .map_err(|e| format!("invalid game number '{}': {}", number, e))
 }
 
-fn extract_number_of_color(line: &str, color: &str) -> Vec<i32> {
+/// Every count drawn of `color`, the number right before each " <color>".
+fn extract_number_of_color(line: &str, color: &str) -> std::result::Result<Vec<i32>, String> {
     let color_formatted_with_white_space = format!(" {}", color);
-    let re_color =
-        Regex::new(format!("(\\d*){}", color_formatted_with_white_space).as_str()).unwrap();
-
-    re_color
-        .find_iter(line)
-        .map(|m| {
-            m.as_str()
-                .replace(color_formatted_with_white_space.as_str(), "")
-                .parse::<i32>()
-                .unwrap()
+
+    line.match_indices(color_formatted_with_white_space.as_str())
+        .map(|(index, _)| {
+            let before = &line[..index];
+            let digits = before.len() - before.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+            let number = &before[index - digits..];
+            number.parse::<i32>().map_err(|e| {
+                format!(
+                    "invalid number of {} in '{}{}': {}",
+                    color, number, color_formatted_with_white_space, e
+                )
+            })
         })
-        .collect::<Vec<i32>>()
+        .collect::<std::result::Result<Vec<i32>, String>>()
 }
 
-pub(crate) fn run_day_2_bonus(input_file: &str) -> i32 {
-    let lines = read_file(input_file);
+/// The largest draw of a color, 0 if the color never showed up.
+fn max_of(draws: &[i32]) -> i32 {
+    draws.iter().max().copied().unwrap_or(0)
+}
 
-    let mut sum = 0;
-    for line in lines.iter() {
-        if line.is_empty() {
-            continue;
-        }
+/// One line of the input: the game number and every count drawn for each color.
+pub struct Game {
+    number: i32,
+    blues: Vec<i32>,
+    reds: Vec<i32>,
+    greens: Vec<i32>,
+}
 
-        let v_blues = extract_number_of_color(line, "blue");
-        let v_red = extract_number_of_color(line, "red");
-        let v_green = extract_number_of_color(line, "green");
+impl Game {
+    fn parse(line: &str) -> std::result::Result<Game, String> {
+        Ok(Game {
+            number: extract_game_number(line)?,
+            blues: extract_number_of_color(line, "blue")?,
+            reds: extract_number_of_color(line, "red")?,
+            greens: extract_number_of_color(line, "green")?,
+        })
+    }
 
-        print!("B: {:?} / R: {:?} / G {:?} => ", v_blues, v_red, v_green);
+    /// The fewest cubes of each color that make the game possible, multiplied together.
+    fn power(&self) -> i32 {
+        max_of(&self.blues) * max_of(&self.reds) * max_of(&self.greens)
+    }
+}
+
+/// The bag content the Elf asks about in part 1.
+pub struct Day2 {
+    pub max_red: i32,
+    pub max_green: i32,
+    pub max_blue: i32,
+}
 
-        sum += v_blues.iter().max().unwrap()
-            * v_red.iter().max().unwrap()
-            * v_green.iter().max().unwrap();
+impl Day2 {
+    pub const fn new(max_red: i32, max_green: i32, max_blue: i32) -> Self {
+        Day2 {
+            max_red,
+            max_green,
+            max_blue,
+        }
+    }
+
+    fn is_possible(&self, game: &Game) -> bool {
+        max_of(&game.blues) <= self.max_blue
+            && max_of(&game.greens) <= self.max_green
+            && max_of(&game.reds) <= self.max_red
     }
-    println!("Somme; {}", sum);
-    return sum;
 }
 
-pub(crate) fn run_day_2(
-    input_file: &str,
-    max_red: usize,
-    max_blue: usize,
-    max_green: usize,
-) -> i32 {
-    let lines = read_file(input_file);
-    let re_game_number = Regex::new(r"Game (\d*):.*").expect("Game Number Regex is wrong");
-
-    let mut sum = 0;
-    for (_i, line) in lines.iter().enumerate() {
-        if line.is_empty() {
-            continue;
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Game>> {
+        parse::lines(input, Game::parse)
+    }
+
+    fn part1(&self, games: &Vec<Game>) -> Result<Answer> {
+        let mut sum = 0;
+        for game in games {
+            let possible = self.is_possible(game);
+            if possible {
+                sum += game.number;
+            }
+            trace!(
+                "B: {:?} / R: {:?} / G {:?} => {}",
+                game.blues,
+                game.reds,
+                game.greens,
+                if possible { "OK" } else { "NOP" }
+            );
         }
+        debug!("Somme; {}", sum);
+        Ok(sum.into())
+    }
 
-        let v_blues = extract_number_of_color(line, "blue");
-        let v_red = extract_number_of_color(line, "red");
-        let v_green = extract_number_of_color(line, "green");
-
-        print!("B: {:?} / R: {:?} / G {:?} => ", v_blues, v_red, v_green);
-        if v_blues.iter().max().unwrap() <= &(max_blue as i32)
-            && v_green.iter().max().unwrap() <= &(max_green as i32)
-            && v_red.iter().max().unwrap() <= &(max_red as i32)
-        {
-            let game_number = extract_game_number(re_game_number.clone(), &line)
-                .expect("Could not parse game number of line");
-
-            sum += game_number;
-            println!("OK");
-        } else {
-            println!("NOP");
+    fn part2(&self, games: &Vec<Game>) -> Result<Answer> {
+        let mut sum = 0;
+        for game in games {
+            let power = game.power();
+            sum += power;
+            trace!(
+                "B: {:?} / R: {:?} / G {:?} => {}",
+                game.blues,
+                game.reds,
+                game.greens,
+                power
+            );
         }
+        debug!("Somme; {}", sum);
+        Ok(sum.into())
+    }
+
+    fn streaming(&self) -> Option<&dyn Streaming> {
+        Some(self)
     }
-    println!("Somme; {}", sum);
-    return sum;
 }
 
-fn read_file(input_file: &str) -> Vec<String> {
-    let lines = fs::read_to_string(format!("src/day_2/{}", input_file)).expect("Mauvais chemin?");
-    lines
-        .split("\n")
-        .into_iter()
-        .map(|s| String::from(s))
-        .collect::<Vec<String>>()
+impl Streaming for Day2 {
+    fn solve_reader(&self, reader: &mut dyn BufRead, part: Part) -> Result<Answer> {
+        let mut sum: i64 = 0;
+        parse::each_line(reader, |line| {
+            let game = Game::parse(line)?;
+            sum += match part {
+                Part::One if self.is_possible(&game) => i64::from(game.number),
+                Part::One => 0,
+                Part::Two => i64::from(game.power()),
+            };
+            Ok(())
+        })?;
+        debug!("Somme; {}", sum);
+        Ok(sum.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{extract_game_number, extract_number_of_color, run_day_2, run_day_2_bonus};
-    use regex::Regex;
+    use super::{extract_game_number, extract_number_of_color, Day2};
+    use aoc_common::answer::Answer;
+    use aoc_common::error::Error;
+    use aoc_common::solution::{Part, Solution, Streaming};
+
+    const DAY_2: Day2 = Day2::new(12, 13, 14);
 
     #[test]
     fn test_example() {
-        assert_eq!(run_day_2("example.txt", 12, 14, 13), 8);
+        let games = DAY_2.parse(include_str!("example.txt")).unwrap();
+        assert_eq!(DAY_2.part1(&games).unwrap(), Answer::from(8));
     }
 
     #[test]
     fn test_example_bonus() {
-        assert_eq!(run_day_2_bonus("example.txt"), 2286);
+        let games = DAY_2.parse(include_str!("example.txt")).unwrap();
+        assert_eq!(DAY_2.part2(&games).unwrap(), Answer::from(2286));
+    }
+
+    #[test]
+    fn test_streaming() {
+        for (part, expected) in [(Part::One, 8), (Part::Two, 2286)] {
+            let mut example = include_str!("example.txt").as_bytes();
+            assert_eq!(
+                DAY_2.solve_reader(&mut example, part).unwrap(),
+                Answer::from(expected)
+            );
+        }
+        let mut bad = "Game 1: 3 blue\nGme 2: 1 blue\n".as_bytes();
+        assert!(matches!(
+            DAY_2.solve_reader(&mut bad, Part::One),
+            Err(Error::Parse(e)) if e.line == 2
+        ));
     }
 
     #[test]
     fn test_extract_number_colors() {
         assert_eq!(
             extract_number_of_color("9 blue, 1 green, 20 red; 9 green, 20 red, 16 blue;", "blue"),
-            vec![9, 16]
+            Ok(vec![9, 16])
         );
 
         assert_eq!(
             extract_number_of_color("9 blue, 1 green, 20 red; 9 green, 20 red, 16 blue;", "red"),
-            vec![20, 20]
+            Ok(vec![20, 20])
         );
 
         assert_eq!(
@@ -128,29 +206,42 @@ mod tests {
                 "9 blue, 1 green, 20 red; 9 green, 20 red, 16 blue;",
                 "green"
             ),
-            vec![1, 9]
+            Ok(vec![1, 9])
         );
 
         assert_eq!(
             extract_number_of_color("Game 78: 4 red, 8 blue, 2 green; 7 blue, 3 green, 7 red; 3 green, 13 blue; 3 red, 4 green", "blue"),
-            vec![8, 7, 13]
+            Ok(vec![8, 7, 13])
         )
     }
 
     #[test]
     fn test_extract_game_number() {
-        let re_game_number = Regex::new(r"e (\d*):").expect("Game Number Regex is wrong");
         assert_eq!(
-            extract_game_number(re_game_number.clone(), "Game 100: 2 blue, 12 green; 6 aaa"),
+            extract_game_number("Game 100: 2 blue, 12 green; 6 aaa"),
             Ok(100)
         );
         assert_eq!(
-            extract_game_number(re_game_number.clone(), "Game 1: 2 blue, 12 green; 6 aaa"),
+            extract_game_number("Game 1: 2 blue, 12 green; 6 aaa"),
             Ok(1)
         );
         assert_eq!(
-            extract_game_number(re_game_number.clone(), "Game 56: 2 blue, 12 green; 6 aaa"),
+            extract_game_number("Game 56: 2 blue, 12 green; 6 aaa"),
             Ok(56)
         );
+        assert!(extract_game_number("Gme 2: 1 blue").is_err());
+        assert!(extract_game_number("Game x: 1 blue").is_err());
+    }
+
+    #[test]
+    fn test_parse_error_points_at_line() {
+        let input = "Game 1: 3 blue, 4 red\nGme 2: 1 blue\n";
+        match DAY_2.parse(input) {
+            Err(Error::Parse(e)) => {
+                assert_eq!(e.line, 2);
+                assert_eq!(e.text, "Gme 2: 1 blue");
+            }
+            _ => panic!("expected a parse error"),
+        }
     }
 }
@@ -1,55 +1,99 @@
-use std::collections::{HashMap, HashSet};
-use std::fs;
+use aoc_common::answer::Answer;
+use aoc_common::error::{Error, Result};
+use aoc_common::parse;
+use aoc_common::solution::{Part, Solution, Streaming};
+use log::debug;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::BufRead;
 
-pub(crate) fn run_day_4(file_name: &str) -> i32 {
-    let lines = fs::read_to_string(format!("src/day_4/{}", file_name))
-        .expect("Should have been able to read this");
+/// The winning numbers and the numbers you have, for each card in order.
+pub type Card = (HashSet<i32>, HashSet<i32>);
 
-    let mut sum = 0;
-    for line in lines.split('\n') {
-        if line.is_empty() {
-            continue;
-        }
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
 
-        let vec_of_ints = extract_into_arrays(line).expect("Could not extract arrays");
-        sum += count_winners(&vec_of_ints[0], &vec_of_ints[1]);
+    fn parse(&self, input: &str) -> Result<Vec<Card>> {
+        parse::lines(input, card)
     }
 
-    println!("{} sum: {}", file_name, sum);
-    return sum;
-}
+    fn part1(&self, cards: &Vec<Card>) -> Result<Answer> {
+        let sum: i32 = cards
+            .iter()
+            .map(|(winners, you_have)| count_winners(winners, you_have))
+            .sum();
 
-pub(crate) fn run_day_4_bonus(file_name: &str, number_of_cards: u32) -> u32 {
-    let lines = fs::read_to_string(format!("src/day_4/{}", file_name))
-        .expect("Should have been able to read this");
+        debug!("sum: {}", sum);
+        Ok(sum.into())
+    }
+
+    fn part2(&self, cards: &Vec<Card>) -> Result<Answer> {
+        let mut hmap = generate_hash_map(cards.len() as u32);
 
-    let mut hmap = generate_hash_map(number_of_cards);
+        for (i, (winners, you_have)) in cards.iter().enumerate() {
+            let card_number = (i + 1) as u32;
+            let copies = count_winners_bonus(card_number, winners, you_have);
+            let number_copies_of_current_i = get_number_of_copies(&hmap, &card_number)
+                .ok_or_else(|| Error::solve(format!("no copies of card {}", card_number)))?;
 
-    for (i, line) in lines.split('\n').enumerate() {
-        if line.is_empty() {
-            continue;
+            increment_hashmap(&mut hmap, copies, number_copies_of_current_i);
         }
 
-        let vec_of_ints = extract_into_arrays(line).expect("Could not extract arrays");
-        let card_number = (i + 1) as u32;
-        let copies = count_winners_bonus(card_number, &vec_of_ints[0], &vec_of_ints[1]);
-        let number_copies_of_current_i = get_number_of_copies(&hmap, &card_number);
+        let sum = hmap.into_values().sum::<u32>();
 
-        increment_hashmap(&mut hmap, copies.clone(), number_copies_of_current_i);
+        debug!("sum: {}", sum);
+        Ok(sum.into())
     }
 
-    let sum = hmap.into_values().sum::<u32>();
+    fn streaming(&self) -> Option<&dyn Streaming> {
+        Some(self)
+    }
+}
 
-    println!("{} sum: {}", file_name, sum);
-    return sum;
+impl Streaming for Day4 {
+    /// Part 2 only remembers the copies won for the next few cards, never more than
+    /// the most numbers a card can match.
+    fn solve_reader(&self, reader: &mut dyn BufRead, part: Part) -> Result<Answer> {
+        let mut sum: u64 = 0;
+        let mut won: VecDeque<u64> = VecDeque::new();
+        parse::each_line(reader, |line| {
+            let (winners, you_have) = card(line)?;
+            match part {
+                Part::One => sum += count_winners(&winners, &you_have) as u64,
+                Part::Two => {
+                    let copies = 1 + won.pop_front().unwrap_or(0);
+                    sum += copies;
+                    let matches = winners.intersection(&you_have).count();
+                    if won.len() < matches {
+                        won.resize(matches, 0);
+                    }
+                    for next in won.iter_mut().take(matches) {
+                        *next += copies;
+                    }
+                }
+            }
+            Ok(())
+        })?;
+
+        debug!("sum: {}", sum);
+        Ok(sum.into())
+    }
 }
 
-fn get_number_of_copies(hmap: &HashMap<u32, u32>, card_number: &u32) -> u32 {
-    *hmap.get(&card_number).expect("Gone too far in loop?")
+fn card(line: &str) -> std::result::Result<Card, String> {
+    let mut vec_of_ints = extract_into_arrays(line)?;
+    let you_have = vec_of_ints.pop().unwrap_or_default();
+    let winners = vec_of_ints.pop().unwrap_or_default();
+    Ok((winners, you_have))
 }
 
-fn generate_hash_map(max_card:u32) -> HashMap<u32, u32> {
-    HashMap::from_iter((1..max_card+1).map(|i| (i, 1_u32)))
+fn get_number_of_copies(hmap: &HashMap<u32, u32>, card_number: &u32) -> Option<u32> {
+    hmap.get(card_number).copied()
+}
+
+fn generate_hash_map(max_card: u32) -> HashMap<u32, u32> {
+    HashMap::from_iter((1..max_card + 1).map(|i| (i, 1_u32)))
 }
 
 fn increment_hashmap(hmap: &mut HashMap<u32, u32>, copies: Vec<u32>, ntimes: u32) {
@@ -62,29 +106,28 @@ fn increment_hashmap(hmap: &mut HashMap<u32, u32>, copies: Vec<u32>, ntimes: u32
     }
 }
 
-fn extract_into_arrays(line: &str) -> Result<Vec<HashSet<i32>>, &'static str> {
+fn extract_into_arrays(line: &str) -> std::result::Result<Vec<HashSet<i32>>, String> {
     if let Some(index_colon) = line.find(':') {
         let s = &line[index_colon + 1..];
         let vec: Vec<HashSet<i32>> = s
             .split('|')
-            .map(|s| {
-                s.split_ascii_whitespace()
-                    .map(|sub| return sub.parse::<i32>().unwrap())
-                    .collect::<HashSet<i32>>()
-            })
-            .collect::<Vec<HashSet<i32>>>();
+            .map(parse::numbers)
+            .collect::<std::result::Result<Vec<HashSet<i32>>, String>>()?;
+        if vec.len() != 2 {
+            return Err(String::from("expected exactly one '|'"));
+        }
         return Ok(vec);
     }
-    return Err("Could not find ':'");
+    Err(String::from("Could not find ':'"))
 }
 
 fn count_winners(vec_of_winners: &HashSet<i32>, vec_you_have: &HashSet<i32>) -> i32 {
-    let cpt = vec_of_winners.intersection(&vec_you_have).count();
-    return if cpt > 0 {
+    let cpt = vec_of_winners.intersection(vec_you_have).count();
+    if cpt > 0 {
         2_i32.pow((cpt - 1) as u32)
     } else {
         0
-    };
+    }
 }
 
 fn count_winners_bonus(
@@ -92,28 +135,46 @@ fn count_winners_bonus(
     vec_of_winners: &HashSet<i32>,
     vec_you_have: &HashSet<i32>,
 ) -> Vec<u32> {
-    let cpt = vec_of_winners.intersection(&vec_you_have).count();
+    let cpt = vec_of_winners.intersection(vec_you_have).count();
 
     if cpt > 0 {
         return (card_number + 1..card_number + 1 + cpt as u32).collect::<Vec<u32>>();
     }
 
-    return vec![];
+    vec![]
 }
 
 #[cfg(test)]
 mod test {
-    use super::{count_winners, count_winners_bonus, extract_into_arrays, run_day_4, generate_hash_map, increment_hashmap, run_day_4_bonus};
+    use super::{
+        count_winners, count_winners_bonus, extract_into_arrays, generate_hash_map,
+        increment_hashmap, Day4,
+    };
+    use aoc_common::answer::Answer;
+    use aoc_common::solution::{Part, Solution, Streaming};
     use std::collections::HashSet;
 
     #[test]
     fn test_example() {
-        assert_eq!(run_day_4("example.txt"), 13);
+        let cards = Day4.parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Day4.part1(&cards).unwrap(), Answer::from(13));
     }
 
     #[test]
     fn test_example_bonus() {
-        assert_eq!(run_day_4_bonus("example.txt", 6), 30);
+        let cards = Day4.parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Day4.part2(&cards).unwrap(), Answer::from(30));
+    }
+
+    #[test]
+    fn test_streaming() {
+        for (part, expected) in [(Part::One, 13), (Part::Two, 30)] {
+            let mut example = include_str!("example.txt").as_bytes();
+            assert_eq!(
+                Day4.solve_reader(&mut example, part).unwrap(),
+                Answer::from(expected)
+            );
+        }
     }
 
     #[test]
@@ -125,6 +186,9 @@ mod test {
                 HashSet::from([83, 86, 6, 31, 17, 9, 48, 53])
             ])
         );
+        assert!(extract_into_arrays("Card 1 41 48 | 83 86").is_err());
+        assert!(extract_into_arrays("Card 1: 41 4x | 83 86").is_err());
+        assert!(extract_into_arrays("Card 1: 41 48 83 86").is_err());
     }
 
     #[test]
@@ -190,7 +254,7 @@ mod test {
 
         let mut keys: Vec<u32> = hmap.clone().into_keys().collect::<Vec<u32>>();
         keys.sort_unstable();
-        assert_eq!(keys, vec![1,2,3,4,5]);
+        assert_eq!(keys, vec![1, 2, 3, 4, 5]);
 
         let values: Vec<u32> = hmap.clone().into_values().collect::<Vec<u32>>();
         assert_eq!(values, vec![1, 1, 1, 1, 1]);
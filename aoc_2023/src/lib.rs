@@ -0,0 +1,129 @@
+//! Advent of Code 2023 solutions, usable without the command line.
+//!
+//! Each day is a [`Solution`]: parse the text of a puzzle input once, then solve either part.
+//!
+//! ```
+//! use aoc_2023::day_4::Day4;
+//! use aoc_2023::{Answer, Solution};
+//!
+//! let cards = Day4.parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n").unwrap();
+//! assert_eq!(Day4.part1(&cards).unwrap(), Answer::from(8));
+//! ```
+//!
+//! [`solve`] does the same for any day of the [`registry`]. The types shared by every year
+//! come from `aoc_common`.
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod registry;
+
+pub use aoc_common::{Answer, Error, Part, Result, Runner, Solution, Streaming};
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: aoc_common::alloc::Counting = aoc_common::alloc::Counting;
+
+/// Solve one part of a registered day on `input`, the text of a puzzle input.
+pub fn solve(year: u16, day: u8, part: Part, input: &str) -> Result<Answer> {
+    let found = registry::find(year, day).ok_or(Error::MissingDay { year, day })?;
+    found.solve(part, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve, Answer, Error, Part, Streaming};
+    use aoc_common::alloc::measure;
+    use std::io::{self, BufReader, Read};
+
+    /// `line` over and over, without ever holding more than one copy of it.
+    struct Repeat {
+        line: &'static [u8],
+        left: usize,
+        offset: usize,
+    }
+
+    impl Read for Repeat {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.left == 0 {
+                return Ok(0);
+            }
+            let rest = &self.line[self.offset..];
+            let n = rest.len().min(buf.len());
+            buf[..n].copy_from_slice(&rest[..n]);
+            self.offset += n;
+            if self.offset == self.line.len() {
+                self.offset = 0;
+                self.left -= 1;
+            }
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let example = include_str!("day_4/example.txt");
+        assert_eq!(
+            solve(2023, 4, Part::One, example).unwrap(),
+            Answer::from(13)
+        );
+        assert_eq!(
+            solve(2023, 4, Part::Two, example).unwrap(),
+            Answer::from(30)
+        );
+        assert!(matches!(
+            solve(2023, 3, Part::Two, example),
+            Err(Error::NotSolved(Part::Two))
+        ));
+        assert!(matches!(
+            solve(2023, 25, Part::One, example),
+            Err(Error::MissingDay { day: 25, .. })
+        ));
+    }
+
+    #[test]
+    fn test_streaming_memory_does_not_grow() {
+        let day_2 = crate::day_2::Day2::new(12, 13, 14);
+        let cases: [(&dyn Streaming, &[u8], u64); 3] = [
+            (&crate::day_1::Day1::ENGLISH, b"xtwone3four\n", 33),
+            (&day_2, b"Game 7: 3 blue, 4 red; 2 green\n", 7),
+            (&crate::day_4::Day4, b"Card 1: 1 2 3 | 3 1 9\n", 2),
+        ];
+        for (day, line, per_line) in cases {
+            let mut peaks = vec![];
+            for lines in [100, 100_000] {
+                let repeat = Repeat {
+                    line,
+                    left: lines,
+                    offset: 0,
+                };
+                let (answer, stats) =
+                    measure(|| day.solve_reader(&mut BufReader::new(repeat), Part::One));
+                assert_eq!(answer.unwrap(), Answer::from(per_line * lines as u64));
+                peaks.push(stats.unwrap().peak);
+            }
+            // A thousand times more input, read through the same buffer.
+            assert!(peaks[1] <= peaks[0] + 1024, "{:?}", peaks);
+        }
+    }
+
+    #[test]
+    fn test_registered_days_stream() {
+        let streaming: Vec<u8> = crate::registry::DAYS
+            .iter()
+            .filter(|day| day.solution.streaming().is_some())
+            .map(|day| day.day)
+            .collect();
+        assert_eq!(streaming, vec![1, 2, 4]);
+
+        let day_4 = crate::registry::find(2023, 4).unwrap();
+        let mut reader = BufReader::new(&b"Card 1: 1 2 3 | 3 1 9\n"[..]);
+        let answer = day_4
+            .solution
+            .streaming()
+            .unwrap()
+            .solve_reader(&mut reader, Part::Two);
+        assert_eq!(answer.unwrap(), Answer::from(1));
+    }
+}
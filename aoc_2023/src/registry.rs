@@ -0,0 +1,38 @@
+use crate::{day_1, day_2, day_3, day_4};
+use aoc_common::Day;
+
+/// Day 1 may own a vocabulary, which a constant cannot drop, so it is kept in a static.
+static DAY_1: day_1::Day1 = day_1::Day1::ENGLISH;
+
+/// Every implemented day. A new day only needs its module and one line here.
+pub const DAYS: &[Day] = &[
+    Day::new(2023, 1, &DAY_1),
+    Day::new(2023, 2, &day_2::Day2::new(12, 13, 14)),
+    Day::new(2023, 3, &day_3::Day3),
+    Day::new(2023, 4, &day_4::Day4),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, DAYS};
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2023, 3).map(|d| d.day), Some(3));
+        assert!(find(2023, 25).is_none());
+        assert!(find(2022, 1).is_none());
+    }
+
+    #[test]
+    fn test_no_duplicates() {
+        for (i, a) in DAYS.iter().enumerate() {
+            for b in &DAYS[i + 1..] {
+                assert!((a.year, a.day) != (b.year, b.day));
+            }
+        }
+    }
+}
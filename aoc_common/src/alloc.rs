@@ -0,0 +1,140 @@
+//! A global allocator that counts what each thread allocates, so that the runner can
+//! report the memory used by each stage. It only counts once a binary installs it:
+//!
+//! ```
+//! #[global_allocator]
+//! static ALLOCATOR: aoc_common::alloc::Counting = aoc_common::alloc::Counting;
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The system allocator, counting allocations and live bytes of the calling thread.
+pub struct Counting;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    // Signed: a thread can free what another one allocated.
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+fn grow(size: usize) {
+    ALLOCATIONS.with(|n| n.set(n.get() + 1));
+    BYTES.with(|n| n.set(n.get() + size as u64));
+    change_live(size as i64);
+}
+
+fn change_live(delta: i64) {
+    let live = LIVE.with(|live| {
+        live.set(live.get() + delta);
+        live.get()
+    });
+    PEAK.with(|peak| peak.set(peak.get().max(live)));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        change_live(-(layout.size() as i64));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            // Counted as freeing the old block and allocating the new one.
+            change_live(-(layout.size() as i64));
+            grow(new_size);
+        }
+        new
+    }
+}
+
+/// What a stage allocated on its thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Highest memory in use during the stage, above what was in use before it.
+    pub peak: u64,
+}
+
+/// Run `f` and count what it allocates, or `None` when [`Counting`] is not installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let allocations = ALLOCATIONS.with(Cell::get);
+    let bytes = BYTES.with(Cell::get);
+    let live = LIVE.with(Cell::get);
+    let outer_peak = PEAK.with(|peak| peak.replace(live));
+
+    let value = f();
+
+    let peak = PEAK.with(|peak| {
+        let inner = peak.get();
+        peak.set(outer_peak.max(inner));
+        inner
+    });
+    let stats = INSTALLED.load(Ordering::Relaxed).then(|| AllocStats {
+        allocations: ALLOCATIONS.with(Cell::get) - allocations,
+        bytes: BYTES.with(Cell::get) - bytes,
+        peak: (peak - live).max(0) as u64,
+    });
+    (value, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{measure, Counting};
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            let small = vec![0u8; 100];
+            drop(small);
+            let big: Vec<u64> = Vec::with_capacity(1000);
+            big.capacity()
+        });
+        assert_eq!(len, 1000);
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 8100);
+        assert_eq!(stats.peak, 8000);
+    }
+
+    #[test]
+    fn test_measure_nested() {
+        let (_, outer) = measure(|| {
+            let kept = vec![0u8; 64];
+            let (_, inner) = measure(|| vec![0u8; 16]);
+            assert_eq!(inner.unwrap().allocations, 1);
+            kept
+        });
+        let outer = outer.unwrap();
+        assert_eq!(outer.allocations, 2);
+        assert_eq!(outer.peak, 80);
+    }
+}
@@ -0,0 +1,143 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// What a part returns: a number of any width and sign, or some text.
+///
+/// Numbers compare by value whatever their variant, so `Answer::from(42_u8)`
+/// equals `Answer::from(42_i64)`. `Display` gives the form the website expects.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Signed(value as i128)
+            }
+        }
+    )*};
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Unsigned(value as u128)
+            }
+        }
+    )*};
+}
+
+from_signed!(i8, i16, i32, i64, i128, isize);
+from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// The reverse of `Display`: integers come back as numbers, anything else as text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(v) = s.parse::<u128>() {
+            return Ok(Answer::Unsigned(v));
+        }
+        if let Ok(v) = s.parse::<i128>() {
+            return Ok(Answer::Signed(v));
+        }
+        Ok(Answer::Text(s.to_string()))
+    }
+}
+
+impl Answer {
+    /// Every number as an unsigned value when it fits, so both variants compare alike.
+    fn normalized(&self) -> Answer {
+        match self {
+            Answer::Signed(v) if *v >= 0 => Answer::Unsigned(*v as u128),
+            other => other.clone(),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.normalized(), other.normalized()) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.normalized() {
+            Answer::Signed(v) => v.hash(state),
+            Answer::Unsigned(v) => v.hash(state),
+            Answer::Text(s) => s.hash(state),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(v) => write!(f, "{}", v),
+            Answer::Unsigned(v) => write!(f, "{}", v),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-12_i8).to_string(), "-12");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+        assert_eq!(Answer::from("EZFKA").to_string(), "EZFKA");
+    }
+
+    #[test]
+    fn test_from_str_round_trips() {
+        for answer in [
+            Answer::from(8805731_u32),
+            Answer::from(-3_i64),
+            Answer::from(u128::MAX),
+            Answer::from("EZFKA"),
+        ] {
+            assert_eq!(answer.to_string().parse::<Answer>().unwrap(), answer);
+        }
+        assert_eq!("  42\n".parse::<Answer>().unwrap(), Answer::from(42));
+    }
+
+    #[test]
+    fn test_numbers_compare_by_value() {
+        assert_eq!(Answer::from(42_u8), Answer::from(42_i64));
+        assert_eq!(Answer::from(0_i32), Answer::from(0_usize));
+        assert_ne!(Answer::from(-1_i32), Answer::from(1_u32));
+        assert_ne!(Answer::from(42_u8), Answer::from("42"));
+    }
+}
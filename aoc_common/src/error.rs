@@ -0,0 +1,147 @@
+use std::fmt;
+use std::io;
+
+use crate::solution::Part;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// An input or data file could not be read or written.
+    Io { path: String, source: io::Error },
+    /// A line of the input does not look like the puzzle says it should.
+    Parse(ParseError),
+    /// One of our own data files (answers, config...) is malformed.
+    Format { path: String, message: String },
+    /// The input parsed but the solver could not reach an answer.
+    Solve(String),
+    /// adventofcode.com could not be reached or answered something unexpected.
+    Http { url: String, message: String },
+    /// No session token, or the website no longer accepts it.
+    Session(String),
+    /// An answer differs from the one confirmed before.
+    Regression(String),
+    /// An answer was not submitted, or the website did not accept it.
+    Submit(String),
+    /// A new day could not be generated.
+    Scaffold(String),
+    /// The part has no solution yet.
+    NotSolved(Part),
+    /// A day was given an option it does not take.
+    BadOption(String),
+    /// Nothing is registered for this day.
+    MissingDay { year: u16, day: u8 },
+}
+
+/// Where and why an input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Set by the caller once it knows where the input came from.
+    pub file: Option<String>,
+    /// 1-based line number in the input.
+    pub line: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl Error {
+    pub fn io(path: impl Into<String>, source: io::Error) -> Self {
+        Error::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// `line_index` is the 0-based index of the line, as given by `enumerate`.
+    pub fn parse(line_index: usize, text: &str, message: impl Into<String>) -> Self {
+        Error::Parse(ParseError {
+            file: None,
+            line: line_index + 1,
+            text: text.to_string(),
+            message: message.into(),
+        })
+    }
+
+    pub fn solve(message: impl Into<String>) -> Self {
+        Error::Solve(message.into())
+    }
+
+    /// Attach the input file name to a parse error, leaving the other errors untouched.
+    pub fn in_file(self, file: impl Into<String>) -> Self {
+        match self {
+            Error::Parse(e) => Error::Parse(ParseError {
+                file: Some(file.into()),
+                ..e
+            }),
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path, source),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Format { path, message } => write!(f, "{}: {}", path, message),
+            Error::Solve(message) => write!(f, "could not solve: {}", message),
+            Error::Http { url, message } => write!(f, "{}: {}", url, message),
+            Error::Session(message) => write!(f, "{}", message),
+            Error::Regression(message) => write!(f, "{}", message),
+            Error::Submit(message) => write!(f, "{}", message),
+            Error::Scaffold(message) => write!(f, "{}", message),
+            Error::NotSolved(part) => write!(f, "part {} is not solved yet", part),
+            Error::BadOption(message) => write!(f, "{}", message),
+            Error::MissingDay { year, day } => {
+                write!(f, "day {} of {} is not solved yet", day, year)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        write!(
+            f,
+            "{}:{}: {}\n  | {}",
+            file, self.line, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_parse_error_display() {
+        let err = Error::parse(2, "Game x: 1 red", "could not find the game number");
+        assert_eq!(
+            err.to_string(),
+            "<input>:3: could not find the game number\n  | Game x: 1 red"
+        );
+
+        let err = err.in_file("src/day_2/input.txt");
+        assert_eq!(
+            err.to_string(),
+            "src/day_2/input.txt:3: could not find the game number\n  | Game x: 1 red"
+        );
+    }
+
+    #[test]
+    fn test_in_file_keeps_other_errors() {
+        let err = Error::NotSolved(Part::Two).in_file("input.txt");
+        assert_eq!(err.to_string(), "part 2 is not solved yet");
+    }
+}
@@ -0,0 +1,128 @@
+use std::ops::Index;
+
+use crate::error::{Error, Result};
+
+/// A rectangle of cells read from lines of text, stored row after row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// One row per non-empty line, one cell per character.
+    /// Every row must be as wide as the first one.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self> {
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        };
+        for (i, line) in input.split('\n').enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let before = grid.cells.len();
+            grid.cells.extend(line.chars().map(&mut cell));
+            let width = grid.cells.len() - before;
+
+            if grid.height == 0 {
+                grid.width = width;
+            } else if width != grid.width {
+                return Err(Error::parse(
+                    i,
+                    line,
+                    format!(
+                        "expected {} columns like the first line, found {}",
+                        grid.width, width
+                    ),
+                ));
+            }
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.height && col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// Panics if `row` is past the last one, like slice indexing.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {} out of {}", row, self.height);
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // An empty grid has no cells, so the chunk size does not matter then.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The positions around `(row, col)`, diagonals included, that are inside the grid.
+    pub fn neighbours(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let rows = row.saturating_sub(1)..=(row + 1).min(self.height.saturating_sub(1));
+        rows.flat_map(move |r| {
+            let cols = col.saturating_sub(1)..=(col + 1).min(self.width.saturating_sub(1));
+            cols.map(move |c| (r, c))
+        })
+        .filter(move |&position| position != (row, col))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", row, col))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::error::Error;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("467..\n...*.\n..35.\n", |c| c).unwrap();
+        assert_eq!((grid.width(), grid.height()), (5, 3));
+        assert_eq!(grid[(1, 3)], '*');
+        assert_eq!(grid.get(2, 5), None);
+        assert_eq!(grid.row(2), &['.', '.', '3', '5', '.']);
+        assert_eq!(grid.rows().count(), 3);
+
+        let empty = Grid::parse("", |c| c).unwrap();
+        assert_eq!(empty.rows().count(), 0);
+    }
+
+    #[test]
+    fn test_ragged_lines() {
+        assert!(matches!(
+            Grid::parse("467..\n...*\n", |c| c),
+            Err(Error::Parse(e)) if e.line == 2
+        ));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse("abc\ndef\nghi\n", |c| c).unwrap();
+        let around = |row, col| -> String { grid.neighbours(row, col).map(|p| grid[p]).collect() };
+        assert_eq!(around(1, 1), "abcdfghi");
+        assert_eq!(around(0, 0), "bde");
+        assert_eq!(around(2, 1), "defgi");
+    }
+}
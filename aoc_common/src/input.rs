@@ -0,0 +1,243 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::solution::Part;
+
+/// Environment variable pointing at the directory holding a `<year>/day_N` input folder per year.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// The `src/` of the crate of `year`, where its day modules live: `aoc_<year>/src` in the
+/// current directory or the closest parent that has one, so anywhere in the workspace.
+/// Their inputs sit next to them when nothing else is configured.
+pub fn year_dir(year: u16) -> Result<PathBuf> {
+    let cwd = env::current_dir().map_err(|e| Error::io("the current directory", e))?;
+    find_year_dir(&cwd, year)
+}
+
+fn find_year_dir(from: &Path, year: u16) -> Result<PathBuf> {
+    let src = Path::new(&format!("aoc_{}", year)).join("src");
+    match from
+        .ancestors()
+        .map(|dir| dir.join(&src))
+        .find(|dir| dir.is_dir())
+    {
+        Some(dir) => Ok(dir),
+        None => Err(Error::io(
+            src.display().to_string(),
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "not in {} nor its parents; run from the workspace, or give --input-dir or ${}",
+                    from.display(),
+                    INPUT_DIR_ENV
+                ),
+            ),
+        )),
+    }
+}
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &Path) -> Self {
+        if arg == Path::new("-") {
+            Source::Stdin
+        } else {
+            Source::File(arg.to_path_buf())
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        let read = match self {
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        read.map_err(|e| Error::io(self.describe(), e))
+    }
+
+    /// The input through a buffer, to be read one line at a time.
+    pub fn reader(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            Source::File(path) => match fs::File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(e) => Err(Error::io(self.describe(), e)),
+            },
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => String::from("<stdin>"),
+        }
+    }
+}
+
+/// Finds the input files of each day under one directory laid out as `day_N/input.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputLocator {
+    dir: PathBuf,
+}
+
+impl InputLocator {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputLocator { dir: dir.into() }
+    }
+
+    /// `<year>/` under the explicit directory if any, then under `AOC_INPUT_DIR`, so that
+    /// years never share inputs nor answers; else the `src/` of the year's crate.
+    pub fn resolve(explicit_dir: Option<&Path>, year: u16) -> Result<Self> {
+        let root = match explicit_dir {
+            Some(dir) => Some(dir.to_path_buf()),
+            None => env::var_os(INPUT_DIR_ENV)
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from),
+        };
+        match root {
+            Some(root) => Ok(InputLocator::new(root.join(year.to_string()))),
+            None => year_dir(year).map(InputLocator::new),
+        }
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day_{}", day))
+    }
+
+    pub fn input(&self, day: u8) -> PathBuf {
+        self.named(day, "input")
+    }
+
+    /// `day_N/<name>.txt`, the file an input name in the answers file stands for.
+    pub fn named(&self, day: u8, name: &str) -> PathBuf {
+        self.day_dir(day).join(format!("{}.txt", name))
+    }
+
+    /// The confirmed answers live with the inputs they were computed from.
+    pub fn answers_file(&self) -> PathBuf {
+        self.dir.join("answers.toml")
+    }
+
+    /// Wrong guesses and the website's cooldown, so that they are never tried twice.
+    pub fn submissions_file(&self) -> PathBuf {
+        self.dir.join("submissions.toml")
+    }
+
+    /// `example_<part>.txt` when the part has its own example, `example.txt` otherwise.
+    pub fn example(&self, day: u8, part: Part) -> PathBuf {
+        let for_part = self.day_dir(day).join(format!("example_{}.txt", part));
+        if for_part.exists() {
+            for_part
+        } else {
+            self.day_dir(day).join("example.txt")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_year_dir, year_dir, InputLocator, Source};
+    use crate::solution::Part;
+    use std::fs;
+    use std::io::BufRead;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_default_dir_in_the_workspace() {
+        let locator = InputLocator::new(year_dir(2023).unwrap());
+        assert!(locator.input(4).is_absolute());
+        assert!(locator.input(4).exists());
+    }
+
+    #[test]
+    fn test_year_dir_is_found_at_run_time() {
+        let root = std::env::temp_dir().join(format!("aoc_year_dir_{}", std::process::id()));
+        let src = root.join("checkout/aoc_2022/src");
+        fs::create_dir_all(src.join("day_3")).unwrap();
+
+        assert_eq!(find_year_dir(&root.join("checkout"), 2022).unwrap(), src);
+        assert_eq!(find_year_dir(&src.join("day_3"), 2022).unwrap(), src);
+        let err = find_year_dir(&root, 2022).unwrap_err().to_string();
+        assert!(
+            err.contains("aoc_2022/src") && err.contains("--input-dir"),
+            "{}",
+            err
+        );
+        assert!(find_year_dir(&root.join("checkout"), 2023).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_example_per_part() {
+        let locator = InputLocator::new(year_dir(2023).unwrap());
+        assert!(locator
+            .example(1, Part::Two)
+            .ends_with("day_1/example_2.txt"));
+        assert!(locator.example(1, Part::One).ends_with("day_1/example.txt"));
+        assert!(locator.example(4, Part::Two).ends_with("day_4/example.txt"));
+    }
+
+    #[test]
+    fn test_explicit_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc_input_{}", std::process::id()));
+        fs::create_dir_all(dir.join("2023/day_7")).unwrap();
+        fs::write(dir.join("2023/day_7/input.txt"), "32T3K 765\n").unwrap();
+
+        let locator = InputLocator::resolve(Some(&dir), 2023).unwrap();
+        let source = Source::File(locator.input(7));
+        assert_eq!(source.read().unwrap(), "32T3K 765\n");
+        let lines: Vec<String> = source
+            .reader()
+            .unwrap()
+            .lines()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines, vec!["32T3K 765"]);
+        assert!(Source::File(dir.join("day_8/input.txt")).reader().is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_years_under_one_root() {
+        let root = std::env::temp_dir().join(format!("aoc_years_{}", std::process::id()));
+        for (year, input) in [(2022, "A Y\n"), (2023, "Card 1: 1 | 1\n")] {
+            fs::create_dir_all(root.join(format!("{}/day_5", year))).unwrap();
+            fs::write(root.join(format!("{}/day_5/input.txt", year)), input).unwrap();
+        }
+
+        let old = InputLocator::resolve(Some(&root), 2022).unwrap();
+        let new = InputLocator::resolve(Some(&root), 2023).unwrap();
+        assert_eq!(Source::File(old.input(5)).read().unwrap(), "A Y\n");
+        assert_eq!(
+            Source::File(new.input(5)).read().unwrap(),
+            "Card 1: 1 | 1\n"
+        );
+        assert_ne!(old.answers_file(), new.answers_file());
+        assert_ne!(old.submissions_file(), new.submissions_file());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(Source::from_arg(Path::new("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Path::new("a.txt")),
+            Source::File(PathBuf::from("a.txt"))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+//! What every year of the calendar shares: answers and errors, the [`Solution`] trait,
+//! input files, grids and parsing helpers.
+
+pub mod alloc;
+pub mod answer;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod solution;
+
+pub use answer::Answer;
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use solution::{Day, Part, Runner, Solution, Streaming};
@@ -0,0 +1,100 @@
+use std::fmt::Display;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// Parse each non-empty line; a failure points at its line with the message of `parse_line`.
+pub fn lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> std::result::Result<T, String>,
+) -> Result<Vec<T>> {
+    input
+        .split('\n')
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_line(line).map_err(|message| Error::parse(i, line, message)))
+        .collect()
+}
+
+/// Call `each` on every non-empty line of `reader` in turn, reusing one buffer so that
+/// memory does not grow with the input. Failures point at their line as with [`lines`].
+pub fn each_line(
+    mut reader: impl BufRead,
+    mut each: impl FnMut(&str) -> std::result::Result<(), String>,
+) -> Result<()> {
+    let mut buffer = String::new();
+    for i in 0.. {
+        buffer.clear();
+        if reader
+            .read_line(&mut buffer)
+            .map_err(|e| Error::io("<input>", e))?
+            == 0
+        {
+            break;
+        }
+        let line = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        if !line.is_empty() {
+            each(line).map_err(|message| Error::parse(i, line, message))?;
+        }
+    }
+    Ok(())
+}
+
+/// Every whitespace-separated number of `text`, into any collection.
+pub fn numbers<T, C>(text: &str) -> std::result::Result<C, String>
+where
+    T: FromStr,
+    T::Err: Display,
+    C: FromIterator<T>,
+{
+    text.split_ascii_whitespace()
+        .map(|word| {
+            word.parse::<T>()
+                .map_err(|e| format!("invalid number '{}': {}", word, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{each_line, lines, numbers};
+    use crate::error::Error;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_each_line() {
+        let mut sums = vec![];
+        each_line("1 2\n\n3".as_bytes(), |line| {
+            sums.push(numbers::<i32, Vec<i32>>(line)?.iter().sum::<i32>());
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(sums, vec![3, 3]);
+
+        assert!(matches!(
+            each_line("1 2\n3 x\n".as_bytes(), |line| numbers::<i32, Vec<i32>>(line).map(|_| ())),
+            Err(Error::Parse(e)) if e.line == 2 && e.text == "3 x"
+        ));
+    }
+
+    #[test]
+    fn test_lines() {
+        let parsed = lines("1 2\n\n3\n", numbers::<i32, Vec<i32>>).unwrap();
+        assert_eq!(parsed, vec![vec![1, 2], vec![3]]);
+
+        assert!(matches!(
+            lines("1 2\n3 x\n", numbers::<i32, Vec<i32>>),
+            Err(Error::Parse(e)) if e.line == 2 && e.message.starts_with("invalid number 'x'")
+        ));
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(
+            numbers::<u8, HashSet<u8>>(" 41 48  6 41"),
+            Ok(HashSet::from([41, 48, 6]))
+        );
+        assert!(numbers::<u8, Vec<u8>>("-1").is_err());
+    }
+}
@@ -0,0 +1,303 @@
+use crate::alloc::{measure, AllocStats};
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use rayon::prelude::*;
+use std::fmt;
+use std::io::BufRead;
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("'{}' is not a part, expected 1 or 2", s)),
+        }
+    }
+}
+
+/// A day of the calendar: the input is parsed once, then both parts are solved from it.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+        Err(Error::NotSolved(Part::Two))
+    }
+
+    /// The same day solved one line at a time, for days that also implement [`Streaming`]:
+    /// `Some(self)` there.
+    fn streaming(&self) -> Option<&dyn Streaming> {
+        None
+    }
+
+    /// The same day set up with `option`, given on the command line, for days that
+    /// can be solved more than one way. Most days take none.
+    fn with_option(&self, _option: &str) -> Result<Box<dyn Runner>> {
+        Err(Error::BadOption(String::from("this day takes no option")))
+    }
+}
+
+/// A day whose parts only need one line at a time, so that inputs too large to load
+/// can be solved from a file or a pipe with constant memory.
+pub trait Streaming {
+    fn solve_reader(&self, reader: &mut dyn BufRead, part: Part) -> Result<Answer>;
+}
+
+/// The outcome of one part, with the time spent solving it.
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+    /// Only when the counting allocator is installed.
+    pub alloc: Option<AllocStats>,
+}
+
+/// The outcome of one run: how long parsing took, then each requested part.
+#[derive(Debug)]
+pub struct RunReport {
+    pub parse: Duration,
+    /// Only when the counting allocator is installed.
+    pub parse_alloc: Option<AllocStats>,
+    pub parts: Vec<PartReport>,
+}
+
+/// Object-safe view of a [`Solution`], so that days with different `Input` types
+/// can live side by side in the registry.
+pub trait Runner: Send + Sync {
+    /// Parse `input` once and solve each of the requested `parts`, in order.
+    /// Only a parse failure fails the whole run; each part keeps its own result.
+    fn run(&self, input: &str, parts: &[Part]) -> Result<RunReport>;
+
+    /// Like [`Runner::run`], but the parts are solved side by side on the thread pool.
+    /// The reports still come in the order of `parts`.
+    fn run_parallel(&self, input: &str, parts: &[Part]) -> Result<RunReport>;
+
+    /// See [`Solution::streaming`].
+    fn streaming(&self) -> Option<&dyn Streaming>;
+
+    /// See [`Solution::with_option`].
+    fn with_option(&self, option: &str) -> Result<Box<dyn Runner>>;
+}
+
+impl<S> Runner for S
+where
+    S: Solution + Send + Sync,
+    S::Input: Sync,
+{
+    fn run(&self, input: &str, parts: &[Part]) -> Result<RunReport> {
+        let start = Instant::now();
+        let (parsed, parse_alloc) = measure(|| self.parse(input));
+        let parsed = parsed?;
+        let parse = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| solve_part(self, &parsed, part))
+            .collect();
+
+        Ok(RunReport {
+            parse,
+            parse_alloc,
+            parts,
+        })
+    }
+
+    fn run_parallel(&self, input: &str, parts: &[Part]) -> Result<RunReport> {
+        let start = Instant::now();
+        let (parsed, parse_alloc) = measure(|| self.parse(input));
+        let parsed = parsed?;
+        let parse = start.elapsed();
+
+        let parts = parts
+            .par_iter()
+            .map(|&part| solve_part(self, &parsed, part))
+            .collect();
+
+        Ok(RunReport {
+            parse,
+            parse_alloc,
+            parts,
+        })
+    }
+
+    fn streaming(&self) -> Option<&dyn Streaming> {
+        Solution::streaming(self)
+    }
+
+    fn with_option(&self, option: &str) -> Result<Box<dyn Runner>> {
+        Solution::with_option(self, option)
+    }
+}
+
+fn solve_part<S: Solution>(solution: &S, parsed: &S::Input, part: Part) -> PartReport {
+    let start = Instant::now();
+    let (answer, alloc) = measure(|| match part {
+        Part::One => solution.part1(parsed),
+        Part::Two => solution.part2(parsed),
+    });
+    PartReport {
+        part,
+        answer,
+        elapsed: start.elapsed(),
+        alloc,
+    }
+}
+
+/// A registered puzzle: which calendar it belongs to and how to solve it.
+#[derive(Clone)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solution: Solver,
+}
+
+/// The solution of a day: the registered one, or one set up with an option for a run.
+#[derive(Clone)]
+pub enum Solver {
+    Registered(&'static dyn Runner),
+    Configured(Arc<dyn Runner>),
+}
+
+impl Deref for Solver {
+    type Target = dyn Runner;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Solver::Registered(runner) => *runner,
+            Solver::Configured(runner) => runner.as_ref(),
+        }
+    }
+}
+
+impl Day {
+    pub const fn new(year: u16, day: u8, solution: &'static dyn Runner) -> Self {
+        Day {
+            year,
+            day,
+            solution: Solver::Registered(solution),
+        }
+    }
+
+    /// The same day, solved as `option` says: see [`Solution::with_option`].
+    pub fn with_option(&self, option: &str) -> Result<Day> {
+        Ok(Day {
+            year: self.year,
+            day: self.day,
+            solution: Solver::Configured(Arc::from(self.solution.with_option(option)?)),
+        })
+    }
+
+    /// Parse `input` and solve only `part`.
+    pub fn solve(&self, part: Part, input: &str) -> Result<Answer> {
+        let report = self.solution.run(input, &[part])?;
+        match report.parts.into_iter().next() {
+            Some(report) => report.answer,
+            None => Err(Error::NotSolved(part)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day, Part, Runner, Solution};
+    use crate::answer::Answer;
+    use crate::error::{Error, Result};
+
+    struct CountLines;
+
+    impl Solution for CountLines {
+        type Input = Vec<String>;
+
+        fn parse(&self, input: &str) -> Result<Vec<String>> {
+            match input.lines().position(|l| l.is_empty()) {
+                Some(i) => Err(Error::parse(i, "", "empty line")),
+                None => Ok(input.lines().map(String::from).collect()),
+            }
+        }
+
+        fn part1(&self, input: &Vec<String>) -> Result<Answer> {
+            Ok(input.len().into())
+        }
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_runner_solves_requested_parts() {
+        let report = CountLines.run("ab\ncde\n", &Part::ALL).unwrap();
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(report.parts[0].part, Part::One);
+        assert_eq!(report.parts[0].answer.as_ref().unwrap(), &Answer::from(2));
+        assert_eq!(report.parts[1].part, Part::Two);
+        assert!(matches!(
+            report.parts[1].answer,
+            Err(Error::NotSolved(Part::Two))
+        ));
+
+        let report = CountLines.run("ab\ncde\n", &[Part::Two]).unwrap();
+        assert_eq!(report.parts.len(), 1);
+        assert!(report.parts[0].answer.is_err());
+    }
+
+    #[test]
+    fn test_run_parallel_keeps_the_order() {
+        let report = CountLines
+            .run_parallel("ab\ncde\n", &[Part::Two, Part::One])
+            .unwrap();
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(report.parts[0].part, Part::Two);
+        assert!(report.parts[0].answer.is_err());
+        assert_eq!(report.parts[1].part, Part::One);
+        assert_eq!(report.parts[1].answer.as_ref().unwrap(), &Answer::from(2));
+    }
+
+    #[test]
+    fn test_no_option_by_default() {
+        let day = Day::new(2023, 7, &CountLines);
+        assert!(matches!(day.with_option("x"), Err(Error::BadOption(_))));
+        assert_eq!(day.solve(Part::One, "a\nb\n").unwrap(), Answer::from(2));
+    }
+
+    #[test]
+    fn test_runner_stops_on_parse_error() {
+        assert!(matches!(
+            CountLines.run("ab\n\ncde\n", &Part::ALL),
+            Err(Error::Parse(e)) if e.line == 2
+        ));
+    }
+}